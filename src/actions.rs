@@ -0,0 +1,230 @@
+use std::path::PathBuf;
+
+use crate::collectors::{Runtime, container_action, instance_action, prune, run_cmd, run_cmd_in};
+use crate::config::{AwsConfig, DockerConfig};
+
+/// Side-effecting operations triggered from a pane after confirmation
+#[derive(Debug, Clone)]
+pub enum Action {
+    Systemd {
+        unit: String,
+        user: bool,
+        verb: UnitVerb,
+    },
+    Container {
+        docker: DockerConfig,
+        id: String,
+        name: String,
+        verb: ContainerVerb,
+    },
+    Compose {
+        docker: DockerConfig,
+        project: String,
+        working_dir: Option<PathBuf>,
+        config_files: Vec<String>,
+        verb: ComposeVerb,
+    },
+    /// Remove unused `images` (dangling only), `containers`, `volumes` or `build cache`
+    Prune { docker: DockerConfig, kind: String },
+    /// `profile` and `region` are those of the `AwsTarget` the instance was listed under
+    Ec2 {
+        aws: AwsConfig,
+        profile: String,
+        region: String,
+        id: String,
+        name: String,
+        verb: Ec2Verb,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitVerb {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl UnitVerb {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UnitVerb::Start => "start",
+            UnitVerb::Stop => "stop",
+            UnitVerb::Restart => "restart",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerVerb {
+    Start,
+    Stop,
+    Restart,
+    Pause,
+    Unpause,
+    Remove,
+}
+
+impl ContainerVerb {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ContainerVerb::Start => "start",
+            ContainerVerb::Stop => "stop",
+            ContainerVerb::Restart => "restart",
+            ContainerVerb::Pause => "pause",
+            ContainerVerb::Unpause => "unpause",
+            ContainerVerb::Remove => "remove",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComposeVerb {
+    Up,
+    Down,
+    Restart,
+}
+
+impl ComposeVerb {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ComposeVerb::Up => "up",
+            ComposeVerb::Down => "down",
+            ComposeVerb::Restart => "restart",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ec2Verb {
+    Start,
+    Stop,
+    Reboot,
+    Terminate,
+}
+
+impl Ec2Verb {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Ec2Verb::Start => "start",
+            Ec2Verb::Stop => "stop",
+            Ec2Verb::Reboot => "reboot",
+            Ec2Verb::Terminate => "terminate",
+        }
+    }
+
+    /// State shown on the instance until the next refresh reports the real one
+    pub fn transitional_state(self) -> &'static str {
+        match self {
+            Ec2Verb::Start => "pending",
+            Ec2Verb::Stop => "stopping",
+            Ec2Verb::Reboot => "rebooting",
+            Ec2Verb::Terminate => "shutting-down",
+        }
+    }
+}
+
+/// Result of a finished action, reported back to the UI loop
+#[derive(Debug)]
+pub struct ActionOutcome {
+    pub label: String,
+    pub result: Result<String, String>,
+}
+
+impl Action {
+    /// Short human-readable label used in confirmations and status messages
+    pub fn describe(&self) -> String {
+        match self {
+            Action::Systemd { unit, user, verb } => {
+                let scope = if *user { " (user)" } else { "" };
+                format!("{} {unit}{scope}", verb.as_str())
+            }
+            Action::Container { name, verb, .. } => format!("{} container {name}", verb.as_str()),
+            Action::Compose { project, verb, .. } => {
+                format!("compose {} {project}", verb.as_str())
+            }
+            Action::Prune { kind, .. } => format!("prune {kind}"),
+            Action::Ec2 { id, name, verb, .. } => {
+                format!("{} instance {name} ({id})", verb.as_str())
+            }
+        }
+    }
+
+    /// Text to retype before an irreversible action runs; `y` is enough for the rest
+    pub fn confirm_phrase(&self) -> Option<&str> {
+        match self {
+            Action::Ec2 {
+                id,
+                name,
+                verb: Ec2Verb::Terminate,
+                ..
+            } => Some(if name == "unnamed" { id } else { name }),
+            _ => None,
+        }
+    }
+
+    pub async fn run(self) -> ActionOutcome {
+        let label = self.describe();
+        let result = match self {
+            Action::Systemd { unit, user, verb } => {
+                let mut args = Vec::new();
+                if user {
+                    args.push("--user".to_string());
+                }
+                args.push("--no-ask-password".to_string());
+                args.push(verb.as_str().to_string());
+                args.push(unit);
+                run_cmd("systemctl", args)
+                    .await
+                    .map(|_| format!("{label} done"))
+            }
+            Action::Container {
+                docker, id, verb, ..
+            } => container_action(&docker, &id, verb.as_str())
+                .await
+                .map(|_| format!("{label} done")),
+            Action::Compose {
+                docker,
+                project,
+                working_dir,
+                config_files,
+                verb,
+            } => {
+                let mut args = vec!["compose".to_string(), "-p".to_string(), project];
+                for file in config_files {
+                    args.push("-f".to_string());
+                    args.push(file);
+                }
+                args.push(verb.as_str().to_string());
+                if verb == ComposeVerb::Up {
+                    args.push("-d".to_string());
+                }
+                // podman and nerdctl ship the same `compose` subcommand
+                let cli = Runtime::detect(&docker).map_or("docker", |r| r.name());
+                run_cmd_in(cli, args, working_dir.as_deref())
+                    .await
+                    .map(|_| format!("{label} done"))
+            }
+            Action::Prune { docker, kind } => match Runtime::detect(&docker) {
+                Ok(runtime) => prune(&runtime, &kind).await.map(|reclaimed| {
+                    format!(
+                        "{label} done, {:.1} MB reclaimed",
+                        reclaimed as f64 / 1_000_000.0
+                    )
+                }),
+                Err(e) => Err(e),
+            },
+            Action::Ec2 {
+                aws,
+                profile,
+                region,
+                id,
+                verb,
+                ..
+            } => instance_action(&aws, &profile, &region, &id, verb.as_str())
+                .await
+                .map(|state| format!("{label} done, now {state}")),
+        };
+
+        ActionOutcome { label, result }
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+
+use crate::config::LogsConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warn,
+    Debug,
+    Plain,
+}
+
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub source: String,
+    pub text: String,
+    pub severity: Severity,
+}
+
+/// Compiled severity patterns from `[logs]`
+#[derive(Debug, Clone)]
+pub struct SeverityRules {
+    error: Option<Regex>,
+    warn: Option<Regex>,
+    debug: Option<Regex>,
+}
+
+impl SeverityRules {
+    pub fn from_config(cfg: &LogsConfig) -> Self {
+        Self {
+            error: Regex::new(&cfg.error_pattern).ok(),
+            warn: Regex::new(&cfg.warn_pattern).ok(),
+            debug: Regex::new(&cfg.debug_pattern).ok(),
+        }
+    }
+
+    pub fn classify(&self, text: &str) -> Severity {
+        let hit = |re: &Option<Regex>| re.as_ref().is_some_and(|re| re.is_match(text));
+        if hit(&self.error) {
+            Severity::Error
+        } else if hit(&self.warn) {
+            Severity::Warn
+        } else if hit(&self.debug) {
+            Severity::Debug
+        } else {
+            Severity::Plain
+        }
+    }
+}
+
+impl Default for SeverityRules {
+    fn default() -> Self {
+        Self::from_config(&LogsConfig::default())
+    }
+}
+
+/// Bounded scroll-back buffer with a pause/scroll position measured from the newest line
+#[derive(Debug, Clone)]
+pub struct LogBuffer {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+    /// Lines between the bottom of the view and the newest line; 0 follows the tail
+    pub scroll: usize,
+    pub paused: bool,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::new(),
+            capacity: capacity.max(1),
+            scroll: 0,
+            paused: false,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.trim();
+    }
+
+    /// Append a line; while paused the view stays anchored on the same lines
+    pub fn push(&mut self, line: LogLine, counts_for_view: bool) {
+        self.lines.push_back(line);
+        if self.paused && counts_for_view {
+            self.scroll += 1;
+        }
+        self.trim();
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.scroll = 0;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LogLine> {
+        self.lines.iter()
+    }
+
+    pub fn scroll_by(&mut self, delta: isize, visible_len: usize) {
+        let max = visible_len.saturating_sub(1) as isize;
+        self.scroll = (self.scroll as isize + delta).clamp(0, max) as usize;
+        if self.scroll > 0 {
+            self.paused = true;
+        }
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        if !self.paused {
+            self.scroll = 0;
+        }
+    }
+
+    pub fn follow(&mut self) {
+        self.paused = false;
+        self.scroll = 0;
+    }
+
+    /// Hold the view at `scroll` lines above the tail
+    pub fn jump_to(&mut self, scroll: usize) {
+        self.scroll = scroll;
+        self.paused = scroll > 0;
+    }
+
+    /// Drop the oldest lines past capacity; `scroll` counts from the tail so a paused view keeps
+    /// its place, and is only held inside the buffer
+    fn trim(&mut self) {
+        let dropped = self.lines.len().saturating_sub(self.capacity);
+        self.lines.drain(..dropped);
+        self.scroll = self.scroll.min(self.lines.len().saturating_sub(1));
+    }
+}
+
+/// Scroll offset of the nearest line containing `needle` (lowercase), older or newer than the view
+pub fn find_match(
+    lines: &[&LogLine],
+    buffer: &LogBuffer,
+    needle: &str,
+    older: bool,
+) -> Option<usize> {
+    let len = lines.len();
+    if len == 0 || needle.is_empty() {
+        return None;
+    }
+    let anchor = len - 1 - buffer.scroll.min(len - 1);
+    let matches = |idx: &usize| lines[*idx].text.to_ascii_lowercase().contains(needle);
+    let found = if older {
+        // From the tail the newest line itself is a candidate
+        let upper = if buffer.scroll == 0 && !buffer.paused {
+            len
+        } else {
+            anchor
+        };
+        (0..upper).rev().find(matches)
+    } else {
+        (anchor + 1..len).find(matches)
+    };
+    found.map(|idx| len - 1 - idx)
+}
+
+/// `2024-05-01T12:34:56.123456789Z msg` -> `05-01 12:34:56 msg`
+pub fn short_timestamp(line: &str) -> String {
+    let Some((ts, msg)) = line.split_once(' ') else {
+        return line.to_string();
+    };
+    match DateTime::parse_from_rfc3339(ts) {
+        Ok(t) => format!("{} {msg}", t.with_timezone(&Utc).format("%m-%d %H:%M:%S")),
+        Err(_) => line.to_string(),
+    }
+}
+
+/// How much history the container log viewer asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogWindow {
+    pub label: &'static str,
+    pub tail: Option<u32>,
+    pub since_secs: Option<u64>,
+}
+
+pub const LOG_WINDOWS: [LogWindow; 6] = [
+    LogWindow {
+        label: "tail 200",
+        tail: Some(200),
+        since_secs: None,
+    },
+    LogWindow {
+        label: "tail 1000",
+        tail: Some(1000),
+        since_secs: None,
+    },
+    LogWindow {
+        label: "since 10m",
+        tail: None,
+        since_secs: Some(10 * 60),
+    },
+    LogWindow {
+        label: "since 1h",
+        tail: None,
+        since_secs: Some(60 * 60),
+    },
+    LogWindow {
+        label: "since 24h",
+        tail: None,
+        since_secs: Some(24 * 60 * 60),
+    },
+    LogWindow {
+        label: "everything",
+        tail: None,
+        since_secs: None,
+    },
+];
+
+/// Full-screen `docker logs -f` view for one container
+#[derive(Debug, Clone)]
+pub struct LogViewer {
+    pub container_id: String,
+    pub container_name: String,
+    pub buffer: LogBuffer,
+    pub window: usize,
+    pub search: String,
+    pub search_mode: bool,
+    /// Tag carried by lines of the current stream so lines from a replaced stream are dropped
+    pub stream_tag: String,
+}
+
+impl LogViewer {
+    pub fn window(&self) -> LogWindow {
+        LOG_WINDOWS[self.window % LOG_WINDOWS.len()]
+    }
+}
@@ -1,5 +1,11 @@
+mod logs;
 mod state;
 mod types;
 
+pub use logs::{LogLine, LogViewer, Severity};
 pub use state::App;
-pub use types::{DetailModal, LayoutState, NavDir, PaletteCommand, Pane, SystemLayoutMode};
+pub use types::{
+    AwsGroup, AwsRow, ComposeProject, ConfirmModal, DetailModal, DockerRow, DockerSort,
+    LayoutState, NavDir, PaletteCommand, Pane, PrSort, StorageRow, StreamRequest, SystemAlerts,
+    SystemLayoutMode, TerminalCommand,
+};
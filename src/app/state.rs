@@ -1,11 +1,23 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
 use chrono::{DateTime, Utc};
 
-use crate::collectors::DashboardData;
-use crate::config::Config;
+use crate::actions::{Action, ComposeVerb, ContainerVerb, Ec2Verb, UnitVerb};
+use crate::collectors::{
+    AwsInstance, AwsResource, DashboardData, DockerContainer, DockerEvent, ListeningSocket, PrItem,
+    ProcessStat, Runtime,
+};
+use crate::config::{AwsConfig, Config, DockerConfig};
+use crate::streams::StreamLine;
 
+use super::logs::{LogBuffer, LogLine, LogViewer, SeverityRules, find_match, short_timestamp};
 use super::types::{
-    DetailModal, LayoutState, ListCursorState, NavDir, PaletteCommand, Pane, SystemAlerts,
-    SystemLayoutMode, parse_pane,
+    AwsGroup, AwsRow, ComposeProject, ConfirmModal, ContainerHistory, DetailModal, DockerFilter,
+    DockerRow, DockerSort, LayoutState, ListCursorState, ListFilterState, NavDir, PaletteCommand,
+    Pane, PrSort, StorageRow, StreamRequest, SystemAlerts, SystemLayoutMode, TerminalCommand,
+    parse_pane,
 };
 
 #[derive(Debug)]
@@ -22,7 +34,12 @@ pub struct App {
     pub command_input: String,
     pub status: Option<String>,
     pub cursors: ListCursorState,
+    pub filters: ListFilterState,
+    pub filter_mode: bool,
     pub detail_modal: Option<DetailModal>,
+    pub confirm: Option<ConfirmModal>,
+    /// What has been typed so far when the confirmation asks to retype a name
+    pub confirm_input: String,
     pub loading: bool,
     pub spinner_index: usize,
     pub system_flash_ticks: u8,
@@ -34,7 +51,33 @@ pub struct App {
     pub disk_peak: f32,
     pub peak_hold_ticks: u8,
     pub system_layout_mode: SystemLayoutMode,
+    pub system_core_view: bool,
+    pub docker_filter: DockerFilter,
+    pub docker_sort: DockerSort,
+    /// Docker pane shows images, volumes and disk usage instead of containers
+    pub docker_storage_view: bool,
+    pub container_history: HashMap<String, ContainerHistory>,
+    pub collapsed_projects: HashSet<String>,
+    /// Most recent container events, newest last
+    pub docker_events: VecDeque<DockerEvent>,
+    /// Debounced refresh requested by events for containers the last poll did not know about
+    docker_refresh_due: Option<Instant>,
+    pub docker_cfg: DockerConfig,
+    pub aws_cfg: AwsConfig,
+    /// Which AWS resource kind the AWS pane lists
+    pub aws_view: AwsResource,
+    /// Index into `data.prs.views`: 0 is `all`, then the configured filters
+    pub pr_view: usize,
+    pub pr_sort: PrSort,
     pub system_alerts: SystemAlerts,
+    pub logs: LogBuffer,
+    pub log_rules: SeverityRules,
+    pub log_sources: Vec<String>,
+    pub log_source: Option<String>,
+    pub log_viewer: Option<LogViewer>,
+    pub pending_stream: Option<StreamRequest>,
+    pub pending_terminal: Option<TerminalCommand>,
+    stream_seq: u64,
 }
 
 impl App {
@@ -52,7 +95,11 @@ impl App {
             command_input: String::new(),
             status: None,
             cursors: ListCursorState::default(),
+            filters: ListFilterState::default(),
+            filter_mode: false,
             detail_modal: None,
+            confirm: None,
+            confirm_input: String::new(),
             loading: true,
             spinner_index: 0,
             system_flash_ticks: 0,
@@ -64,11 +111,34 @@ impl App {
             disk_peak: 0.0,
             peak_hold_ticks: 0,
             system_layout_mode: SystemLayoutMode::Auto,
+            system_core_view: false,
+            docker_filter: DockerFilter::default(),
+            docker_sort: DockerSort::default(),
+            docker_storage_view: false,
+            container_history: HashMap::new(),
+            collapsed_projects: HashSet::new(),
+            docker_events: VecDeque::new(),
+            docker_refresh_due: None,
+            docker_cfg: DockerConfig::default(),
+            aws_cfg: AwsConfig::default(),
+            aws_view: AwsResource::Ec2,
+            pr_view: 0,
+            pr_sort: PrSort::default(),
             system_alerts: SystemAlerts::default(),
+            logs: LogBuffer::new(2000),
+            log_rules: SeverityRules::default(),
+            log_sources: vec![],
+            log_source: None,
+            log_viewer: None,
+            pending_stream: None,
+            pending_terminal: None,
+            stream_seq: 0,
         }
     }
 
     pub fn apply_config(&mut self, cfg: &Config) {
+        self.docker_cfg = cfg.docker.clone();
+        self.aws_cfg = cfg.aws.clone();
         self.system_layout_mode = parse_layout_mode(&cfg.system_ui.layout_mode);
         self.system_alerts = SystemAlerts {
             cpu_warn_pct: cfg.alerts.cpu_warn_pct,
@@ -78,6 +148,16 @@ impl App {
             stale_warn_secs: cfg.alerts.stale_warn_secs,
             stale_crit_secs: cfg.alerts.stale_crit_secs,
         };
+        self.logs.set_capacity(cfg.logs.buffer_lines);
+        self.log_rules = SeverityRules::from_config(&cfg.logs);
+        self.log_sources = cfg.logs.sources.iter().map(|s| s.name.clone()).collect();
+        if self
+            .log_source
+            .as_ref()
+            .is_some_and(|s| !self.log_sources.contains(s))
+        {
+            self.log_source = None;
+        }
     }
 
     pub fn select_next(&mut self) {
@@ -104,6 +184,9 @@ impl App {
             (Pane::Docker, NavDir::Right) => Pane::Aws,
             (Pane::Aws, NavDir::Right) => Pane::Plugins,
             (Pane::Plugins, NavDir::Right) => Pane::Plugins,
+            (Pane::Ports, NavDir::Right) => Pane::Services,
+            (Pane::Services, NavDir::Right) => Pane::Logs,
+            (Pane::Logs, NavDir::Right) => Pane::Logs,
 
             (Pane::Prs, NavDir::Left) => Pane::System,
             (Pane::System, NavDir::Left) => Pane::Git,
@@ -111,12 +194,21 @@ impl App {
             (Pane::Plugins, NavDir::Left) => Pane::Aws,
             (Pane::Aws, NavDir::Left) => Pane::Docker,
             (Pane::Docker, NavDir::Left) => Pane::Docker,
+            (Pane::Ports, NavDir::Left) => Pane::Ports,
+            (Pane::Services, NavDir::Left) => Pane::Ports,
+            (Pane::Logs, NavDir::Left) => Pane::Services,
 
             (Pane::Git, NavDir::Down) => Pane::Docker,
             (Pane::System, NavDir::Down) => Pane::Aws,
             (Pane::Prs, NavDir::Down) => Pane::Plugins,
+            (Pane::Docker, NavDir::Down) => Pane::Ports,
+            (Pane::Aws, NavDir::Down) => Pane::Services,
+            (Pane::Plugins, NavDir::Down) => Pane::Logs,
             (pane, NavDir::Down) => pane,
 
+            (Pane::Ports, NavDir::Up) => Pane::Docker,
+            (Pane::Services, NavDir::Up) => Pane::Aws,
+            (Pane::Logs, NavDir::Up) => Pane::Plugins,
             (Pane::Docker, NavDir::Up) => Pane::Git,
             (Pane::Aws, NavDir::Up) => Pane::System,
             (Pane::Plugins, NavDir::Up) => Pane::Prs,
@@ -153,6 +245,21 @@ impl App {
         push_bounded(&mut self.mem_history, mem_pct, 64);
         push_bounded(&mut self.disk_history, disk_pct, 64);
 
+        self.container_history
+            .retain(|id, _| data.docker.items.iter().any(|c| c.id == *id));
+        for c in &data.docker.items {
+            let Some(stats) = &c.stats else {
+                continue;
+            };
+            let history = self.container_history.entry(c.id.clone()).or_default();
+            if let Some(cpu) = stats.cpu_pct {
+                push_bounded(&mut history.cpu, cpu.round() as u64, 32);
+            }
+            if let Some(mem) = stats.mem_pct() {
+                push_bounded(&mut history.mem, mem.round() as u64, 32);
+            }
+        }
+
         self.data = data;
         self.normalize_cursors();
         self.last_error = None;
@@ -188,12 +295,16 @@ impl App {
             Pane::Docker | Pane::Aws | Pane::Plugins => {
                 adjust_three_cols(&mut self.layout.bottom_cols_pct, self.selected, step)
             }
+            Pane::Ports | Pane::Services | Pane::Logs => {
+                adjust_three_cols(&mut self.layout.extra_cols_pct, self.selected, step)
+            }
         }
     }
 
     pub fn resize_rows(&mut self, delta: i16) {
         let next = self.layout.top_height_pct as i16 + if delta < 0 { -2 } else { 2 };
-        self.layout.top_height_pct = next.clamp(35, 75) as u16;
+        let max = 100 - self.layout.extra_height_pct as i16 - 20;
+        self.layout.top_height_pct = next.clamp(30, max.max(30)) as u16;
     }
 
     pub fn enter_command_mode(&mut self) {
@@ -206,6 +317,576 @@ impl App {
         self.command_input.clear();
     }
 
+    pub fn enter_filter_mode(&mut self) {
+        if self.selected.supports_filter() {
+            self.filter_mode = true;
+        } else {
+            self.set_status("this pane has no filter");
+        }
+    }
+
+    pub fn exit_filter_mode(&mut self) {
+        self.filter_mode = false;
+    }
+
+    pub fn filter_for(&self, pane: Pane) -> &str {
+        match pane {
+            Pane::Aws => &self.filters.aws,
+            Pane::Ports => &self.filters.ports,
+            Pane::Logs => &self.filters.logs,
+            _ => "",
+        }
+    }
+
+    pub fn push_filter_char(&mut self, c: char) {
+        if let Some(filter) = self.filter_mut_for(self.selected) {
+            filter.push(c);
+        }
+        self.filter_changed();
+    }
+
+    pub fn pop_filter_char(&mut self) {
+        if let Some(filter) = self.filter_mut_for(self.selected) {
+            filter.pop();
+        }
+        self.filter_changed();
+    }
+
+    fn filter_changed(&mut self) {
+        if self.selected == Pane::Logs {
+            // Incremental search: jump to the newest match as the query grows
+            self.logs.follow();
+            self.search_logs(true);
+        }
+        self.normalize_cursors();
+    }
+
+    pub fn push_log_line(&mut self, line: StreamLine) {
+        let in_view = self.log_source.as_ref().is_none_or(|s| *s == line.source);
+        let severity = self.log_rules.classify(&line.text);
+        self.logs.push(
+            LogLine {
+                source: line.source,
+                text: line.text,
+                severity,
+            },
+            in_view,
+        );
+    }
+
+    /// Log lines for the selected source, oldest first
+    pub fn visible_logs(&self) -> Vec<&LogLine> {
+        self.logs
+            .iter()
+            .filter(|l| self.log_source.as_ref().is_none_or(|s| *s == l.source))
+            .collect()
+    }
+
+    pub fn cycle_log_source(&mut self) {
+        self.log_source = match &self.log_source {
+            None => self.log_sources.first().cloned(),
+            Some(current) => self
+                .log_sources
+                .iter()
+                .position(|s| s == current)
+                .and_then(|idx| self.log_sources.get(idx + 1))
+                .cloned(),
+        };
+        self.logs.follow();
+        self.set_status(format!(
+            "log source: {}",
+            self.log_source.as_deref().unwrap_or("all")
+        ));
+    }
+
+    pub fn scroll_logs(&mut self, delta: isize) {
+        let len = self.visible_logs().len();
+        self.logs.scroll_by(delta, len);
+    }
+
+    /// Move the view to the next match of the Logs search, older or newer than the view
+    pub fn search_logs(&mut self, older: bool) {
+        let needle = self.filters.logs.trim().to_ascii_lowercase();
+        if needle.is_empty() {
+            return;
+        }
+
+        match find_match(&self.visible_logs(), &self.logs, &needle, older) {
+            Some(scroll) => self.logs.jump_to(scroll),
+            None => self.set_status(format!("no match for '{needle}'")),
+        }
+    }
+
+    pub fn clear_filter(&mut self) {
+        if let Some(filter) = self.filter_mut_for(self.selected) {
+            filter.clear();
+        }
+        self.normalize_cursors();
+    }
+
+    /// Listening sockets matching the Ports filter (protocol, address, port, pid or process)
+    pub fn visible_ports(&self) -> Vec<&ListeningSocket> {
+        let needle = self.filters.ports.trim().to_lowercase();
+        self.data
+            .ports
+            .items
+            .iter()
+            .filter(|s| {
+                needle.is_empty()
+                    || s.port.to_string().contains(&needle)
+                    || s.protocol.contains(&needle)
+                    || s.local_addr.contains(&needle)
+                    || s.process.to_lowercase().contains(&needle)
+                    || s.pid.is_some_and(|pid| pid.to_string() == needle)
+            })
+            .collect()
+    }
+
+    pub fn visible_containers(&self) -> Vec<&DockerContainer> {
+        let mut items = self
+            .data
+            .docker
+            .items
+            .iter()
+            .filter(|c| match self.docker_filter {
+                DockerFilter::All => true,
+                DockerFilter::Running => c.state == "running",
+                DockerFilter::Unhealthy => c.needs_attention(),
+            })
+            .collect::<Vec<_>>();
+
+        let key = |c: &DockerContainer| -> f64 {
+            let Some(s) = &c.stats else {
+                return -1.0;
+            };
+            match self.docker_sort {
+                DockerSort::Default => 0.0,
+                DockerSort::Cpu => s.cpu_pct.unwrap_or(0.0),
+                DockerSort::Memory => s.mem_used_bytes as f64,
+                DockerSort::Network => (s.net_rx_bytes + s.net_tx_bytes) as f64,
+                DockerSort::BlockIo => (s.blk_read_bytes + s.blk_write_bytes) as f64,
+            }
+        };
+        if self.docker_sort != DockerSort::Default {
+            items.sort_by(|a, b| key(b).total_cmp(&key(a)));
+        }
+        items
+    }
+
+    /// Containers grouped under their Compose project headers; standalone containers come last
+    pub fn docker_rows(&self) -> Vec<DockerRow<'_>> {
+        let containers = self.visible_containers();
+        if !containers.iter().any(|c| c.compose_project().is_some()) {
+            return containers
+                .into_iter()
+                .map(|container| DockerRow::Container {
+                    container,
+                    grouped: false,
+                })
+                .collect();
+        }
+
+        let mut projects: BTreeMap<&str, Vec<&DockerContainer>> = BTreeMap::new();
+        let mut standalone = Vec::new();
+        for c in containers {
+            match c.compose_project() {
+                Some(project) => projects.entry(project).or_default().push(c),
+                None => standalone.push(c),
+            }
+        }
+
+        let mut rows = Vec::new();
+        for (name, members) in projects {
+            // The badge reflects the whole project, not just what the filter shows
+            let all = self
+                .data
+                .docker
+                .items
+                .iter()
+                .filter(|c| c.compose_project() == Some(name))
+                .collect::<Vec<_>>();
+            let first = all.first().copied();
+            let collapsed = self.collapsed_projects.contains(name);
+            rows.push(DockerRow::Project(ComposeProject {
+                name: name.to_string(),
+                working_dir: first
+                    .and_then(|c| c.label("com.docker.compose.project.working_dir"))
+                    .map(str::to_string),
+                config_files: first
+                    .and_then(|c| c.label("com.docker.compose.project.config_files"))
+                    .map(|files| files.split(',').map(str::to_string).collect())
+                    .unwrap_or_default(),
+                total: all.len(),
+                running: all.iter().filter(|c| c.state == "running").count(),
+                failing: all.iter().filter(|c| c.needs_attention()).count(),
+                collapsed,
+            }));
+            if !collapsed {
+                rows.extend(members.into_iter().map(|container| DockerRow::Container {
+                    container,
+                    grouped: true,
+                }));
+            }
+        }
+        rows.extend(
+            standalone
+                .into_iter()
+                .map(|container| DockerRow::Container {
+                    container,
+                    grouped: false,
+                }),
+        );
+        rows
+    }
+
+    /// `system df` summary, then images and volumes, largest first
+    pub fn storage_rows(&self) -> Vec<StorageRow<'_>> {
+        let storage = &self.data.docker.storage;
+        storage
+            .usage
+            .iter()
+            .map(StorageRow::Usage)
+            .chain(storage.images.iter().map(StorageRow::Image))
+            .chain(storage.volumes.iter().map(StorageRow::Volume))
+            .collect()
+    }
+
+    /// Items of the selected AWS view under a header per account/region; failed targets keep
+    /// their header and error. The `/` search matches names, ids, states and the like; header
+    /// counts cover the whole target.
+    pub fn aws_rows(&self) -> Vec<AwsRow<'_>> {
+        const STATE_ORDER: [&str; 6] = [
+            "running",
+            "pending",
+            "stopping",
+            "stopped",
+            "shutting-down",
+            "terminated",
+        ];
+        let needle = self.filters.aws.trim().to_lowercase();
+
+        let aws = &self.data.aws;
+        let mut rows = Vec::new();
+        for target in &aws.targets {
+            // (state counted in the header, text the search looks at, row)
+            let entries: Vec<(&str, String, AwsRow<'_>)> = match self.aws_view {
+                AwsResource::Ec2 => aws
+                    .items
+                    .iter()
+                    .filter(|i| target.contains(*i))
+                    .map(|i| {
+                        let text = [
+                            &i.name,
+                            &i.id,
+                            &i.state,
+                            &i.instance_type,
+                            &i.az,
+                            &i.public_ip,
+                            &i.private_ip,
+                        ]
+                        .map(String::as_str)
+                        .join(" ");
+                        (i.state.as_str(), text, AwsRow::Instance(i))
+                    })
+                    .collect(),
+                AwsResource::Ecs => aws
+                    .ecs
+                    .iter()
+                    .filter(|s| target.contains(*s))
+                    .map(|s| {
+                        let text = format!("{} {} {} {}", s.cluster, s.name, s.status, s.health());
+                        (s.health(), text, AwsRow::Service(s))
+                    })
+                    .collect(),
+                AwsResource::Lambda => aws
+                    .lambdas
+                    .iter()
+                    .filter(|f| target.contains(*f))
+                    .map(|f| {
+                        let text = format!("{} {}", f.name, f.runtime);
+                        (f.runtime.as_str(), text, AwsRow::Function(f))
+                    })
+                    .collect(),
+                AwsResource::Rds => aws
+                    .databases
+                    .iter()
+                    .filter(|d| target.contains(*d))
+                    .map(|d| {
+                        let text = format!(
+                            "{} {} {} {} {}",
+                            d.id, d.status, d.engine, d.class, d.endpoint
+                        );
+                        (d.status.as_str(), text, AwsRow::Database(d))
+                    })
+                    .collect(),
+                AwsResource::Alarms => aws
+                    .alarms
+                    .iter()
+                    .filter(|a| target.contains(*a))
+                    .map(|a| {
+                        let text = format!("{} {} {} {}", a.name, a.namespace, a.metric, a.reason);
+                        ("alarm", text, AwsRow::Alarm(a))
+                    })
+                    .collect(),
+            };
+
+            let error = target.error(self.aws_view);
+            let mut states: Vec<(&str, usize)> = Vec::new();
+            for (state, _, _) in &entries {
+                match states.iter_mut().find(|(s, _)| s == state) {
+                    Some((_, count)) => *count += 1,
+                    None => states.push((state, 1)),
+                }
+            }
+            states.sort_by_key(|(state, _)| {
+                STATE_ORDER
+                    .iter()
+                    .position(|s| s == state)
+                    .unwrap_or(STATE_ORDER.len())
+            });
+            let visible = entries
+                .into_iter()
+                .filter(|(_, text, _)| needle.is_empty() || text.to_lowercase().contains(&needle))
+                .map(|(_, _, row)| row)
+                .collect::<Vec<_>>();
+            if visible.is_empty() && !needle.is_empty() && error.is_none() {
+                continue;
+            }
+            rows.push(AwsRow::Group(AwsGroup {
+                target,
+                error,
+                states,
+            }));
+            rows.extend(visible);
+        }
+        rows
+    }
+
+    /// Cycle the AWS pane between EC2, ECS, Lambda, RDS and alarms
+    pub fn cycle_aws_view(&mut self) {
+        self.aws_view = self.aws_view.next();
+        self.cursors.aws = 0;
+        self.set_status(format!("aws: {} view", self.aws_view.label()));
+    }
+
+    fn docker_row_count(&self) -> usize {
+        if self.docker_storage_view {
+            self.storage_rows().len()
+        } else {
+            self.docker_rows().len()
+        }
+    }
+
+    /// Apply an engine event to the container list right away and remember it for the detail modal
+    pub fn push_docker_event(&mut self, event: DockerEvent) {
+        let items = &mut self.data.docker.items;
+        let known = match items.iter_mut().find(|c| c.id == event.id) {
+            Some(c) => {
+                c.apply_event(&event);
+                true
+            }
+            None => false,
+        };
+        if event.action == "destroy" {
+            items.retain(|c| c.id != event.id);
+        }
+        if !known || matches!(event.action.as_str(), "create" | "destroy") {
+            self.docker_refresh_due
+                .get_or_insert_with(|| Instant::now() + Duration::from_millis(500));
+        }
+
+        let alarming = match event.action.as_str() {
+            "die" => event.detail.as_deref().is_some_and(|d| d != "exit 0"),
+            "oom" => true,
+            "health_status" => event.detail.as_deref() == Some("unhealthy"),
+            _ => false,
+        };
+        if alarming {
+            let detail = event
+                .detail
+                .as_deref()
+                .map_or(String::new(), |d| format!(" ({d})"));
+            self.set_status(format!(
+                "container {}: {}{detail}",
+                event.name, event.action
+            ));
+        }
+
+        self.docker_events.push_back(event);
+        while self.docker_events.len() > 200 {
+            self.docker_events.pop_front();
+        }
+        self.normalize_cursors();
+    }
+
+    /// True once a refresh requested by events is due; a burst of events yields one refresh
+    pub fn take_due_refresh(&mut self) -> bool {
+        match self.docker_refresh_due {
+            Some(due) if due <= Instant::now() => {
+                self.docker_refresh_due = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn toggle_docker_storage_view(&mut self) {
+        self.docker_storage_view = !self.docker_storage_view;
+        self.cursors.docker = 0;
+        self.set_status(if self.docker_storage_view {
+            "docker: images, volumes and disk usage"
+        } else {
+            "docker: containers"
+        });
+    }
+
+    /// Ask to prune the kind of object under the cursor in the storage view
+    pub fn request_prune(&mut self) {
+        let Some(row) = self
+            .current_list_cursor(Pane::Docker)
+            .and_then(|idx| self.storage_rows().into_iter().nth(idx))
+        else {
+            self.set_status("nothing selected");
+            return;
+        };
+        let kind = row.prune_kind().to_string();
+        let usage = self
+            .data
+            .docker
+            .storage
+            .usage
+            .iter()
+            .find(|u| u.kind == kind);
+
+        let action = Action::Prune {
+            docker: self.docker_cfg.clone(),
+            kind: kind.clone(),
+        };
+        let mut lines = vec![format!("{}?", action.describe())];
+        if let Some(usage) = usage {
+            lines.push(format!(
+                "{} unused of {}, about {:.1} MB reclaimable",
+                usage.total - usage.active,
+                usage.total,
+                usage.reclaimable_bytes as f64 / 1_000_000.0
+            ));
+        }
+        lines.push(
+            match kind.as_str() {
+                "images" => "only dangling (untagged) images are removed",
+                "containers" => "all stopped containers will be deleted",
+                "volumes" => "unused volumes, named and anonymous, and their data will be deleted",
+                _ => "unused build cache will be deleted",
+            }
+            .to_string(),
+        );
+        self.confirm = Some(ConfirmModal {
+            title: format!("prune {kind}"),
+            lines,
+            action,
+        });
+    }
+
+    fn selected_docker_row(&self) -> Option<DockerRow<'_>> {
+        if self.docker_storage_view {
+            return None;
+        }
+        let idx = self.current_list_cursor(Pane::Docker)?;
+        self.docker_rows().into_iter().nth(idx)
+    }
+
+    fn selected_container(&self) -> Option<DockerContainer> {
+        match self.selected_docker_row()? {
+            DockerRow::Container { container, .. } => Some(container.clone()),
+            DockerRow::Project(_) => None,
+        }
+    }
+
+    fn toggle_project(&mut self, name: &str) {
+        if !self.collapsed_projects.remove(name) {
+            self.collapsed_projects.insert(name.to_string());
+        }
+        self.normalize_cursors();
+    }
+
+    pub fn cycle_docker_sort(&mut self) {
+        self.docker_sort = self.docker_sort.next();
+        self.set_status(format!("docker: sorted by {}", self.docker_sort.label()));
+    }
+
+    pub fn cycle_docker_filter(&mut self) {
+        self.docker_filter = self.docker_filter.next();
+        self.normalize_cursors();
+        self.set_status(format!("docker: showing {}", self.docker_filter.label()));
+    }
+
+    /// Cycle the PR pane through `all` and the saved filters
+    pub fn cycle_pr_view(&mut self) {
+        let views = &self.data.prs.views;
+        if views.is_empty() {
+            self.set_status("prs: no filters loaded yet");
+            return;
+        }
+        self.pr_view = (self.pr_view + 1) % views.len();
+        self.cursors.prs = 0;
+        let name = views[self.pr_view].name.clone();
+        self.set_status(format!("prs: showing {name}"));
+    }
+
+    pub fn cycle_pr_sort(&mut self) {
+        self.pr_sort = self.pr_sort.next();
+        self.set_status(format!("prs: sorted by {}", self.pr_sort.label()));
+    }
+
+    /// The active PR view's rows in the chosen order
+    pub fn pr_rows(&self) -> Vec<&PrItem> {
+        let mut rows = self.data.prs.items(self.pr_view).iter().collect::<Vec<_>>();
+        match self.pr_sort {
+            PrSort::Updated => rows.sort_by(|a, b| b.updated_at.cmp(&a.updated_at)),
+            PrSort::Review => rows.sort_by(|a, b| {
+                review_rank(a)
+                    .cmp(&review_rank(b))
+                    .then_with(|| b.updated_at.cmp(&a.updated_at))
+            }),
+            PrSort::Age => rows.sort_by(|a, b| a.created_at.cmp(&b.created_at)),
+        }
+        rows
+    }
+
+    /// Focus the System pane and open the process owning the selected socket
+    pub fn jump_to_port_process(&mut self) {
+        let Some(idx) = self.current_list_cursor(Pane::Ports) else {
+            self.set_status("no socket selected");
+            return;
+        };
+        let Some(stat) = self
+            .visible_ports()
+            .get(idx)
+            .and_then(|s| s.process_stat.clone())
+        else {
+            self.set_status("owning process not visible (try running as its user)");
+            return;
+        };
+
+        self.selected = Pane::System;
+        match self
+            .data
+            .system
+            .top_processes
+            .iter()
+            .position(|p| p.pid == stat.pid)
+        {
+            Some(pos) => self.cursors.system = pos,
+            None => {
+                self.set_status(format!(
+                    "pid {} ({}) is outside the top process list",
+                    stat.pid, stat.name
+                ));
+                self.detail_modal = Some(process_detail(&stat));
+            }
+        }
+    }
+
     pub fn parse_command(&self) -> Result<PaletteCommand, String> {
         let raw = self.command_input.trim();
         let mut parts = raw.split_whitespace();
@@ -215,6 +896,7 @@ impl App {
             "refresh" | "r" => Ok(PaletteCommand::Refresh),
             "reload" => Ok(PaletteCommand::ReloadConfig),
             "compact" => Ok(PaletteCommand::ToggleCompact),
+            "cores" => Ok(PaletteCommand::ToggleCoreView),
             "quit" | "q" | "exit" => Ok(PaletteCommand::Quit),
             "focus" | "f" => {
                 let target = parts.next().ok_or("usage: focus <pane>")?;
@@ -255,6 +937,8 @@ impl App {
             Pane::Docker => self.cursors.docker.min(len - 1),
             Pane::Aws => self.cursors.aws.min(len - 1),
             Pane::Plugins => self.cursors.plugins.min(len - 1),
+            Pane::Ports => self.cursors.ports.min(len - 1),
+            Pane::Services => self.cursors.services.min(len - 1),
             _ => 0,
         })
     }
@@ -273,36 +957,31 @@ impl App {
                 let Some(p) = self.data.system.top_processes.get(selected_idx) else {
                     return;
                 };
-
-                DetailModal {
-                    title: format!("Process {} ({})", p.name, p.pid),
-                    lines: vec![
-                        format!("pid: {}", p.pid),
-                        format!("name: {}", p.name),
-                        format!(
-                            "command: {}",
-                            if p.command.is_empty() {
-                                "n/a"
-                            } else {
-                                &p.command
-                            }
-                        ),
-                        format!("cpu: {:.1}%", p.cpu_pct),
-                        format!("memory: {:.1} MB", p.mem_mb),
-                        format!("runtime: {}", format_duration_short(p.runtime_secs)),
-                        format!("io read: {:.1} MB", p.read_mb),
-                        format!("io write: {:.1} MB", p.write_mb),
-                    ],
-                }
+                process_detail(p)
             }
             Pane::Prs => {
-                let Some(pr) = self.data.prs.items.get(selected_idx) else {
+                let Some(pr) = self.pr_rows().get(selected_idx).copied() else {
                     return;
                 };
 
                 let mut lines = vec![
                     format!("#{} {}", pr.number, pr.title),
+                    format!("repo: {}", blank_if_empty(&pr.repo)),
                     format!("author: {}", pr.author),
+                    format!("draft: {}", if pr.draft { "yes" } else { "no" }),
+                    format!(
+                        "review: {}",
+                        pr.review_decision.as_deref().unwrap_or("not required")
+                    ),
+                    format!(
+                        "requested reviewers: {}",
+                        blank_if_empty(&pr.requested_reviewers.join(", "))
+                    ),
+                    format!("checks: {}", pr.checks.as_deref().unwrap_or("none")),
+                    format!("mergeable: {}", blank_if_empty(&pr.mergeable)),
+                    format!("changes: +{} -{}", pr.additions, pr.deletions),
+                    format!("labels: {}", blank_if_empty(&pr.labels.join(", "))),
+                    format!("created: {}", blank_if_empty(&pr.created_at)),
                     format!("updated: {}", blank_if_empty(&pr.updated_at)),
                     format!("url: {}", blank_if_empty(&pr.url)),
                     format!("source: {}", self.data.prs.source),
@@ -320,40 +999,231 @@ impl App {
                     lines,
                 }
             }
+            Pane::Docker if self.docker_storage_view => {
+                match self.storage_rows().into_iter().nth(selected_idx) {
+                    Some(row) => storage_detail(row),
+                    None => return,
+                }
+            }
             Pane::Docker => {
-                let Some(c) = self.data.docker.items.get(selected_idx) else {
-                    return;
+                let c = match self.docker_rows().into_iter().nth(selected_idx) {
+                    Some(DockerRow::Container { container, .. }) => container,
+                    Some(DockerRow::Project(project)) => {
+                        self.toggle_project(&project.name);
+                        return;
+                    }
+                    None => return,
                 };
 
+                let mut lines = vec![
+                    format!("id: {}", blank_if_empty(&c.id)),
+                    format!("name: {}", c.name),
+                    format!("state: {}", c.state),
+                    format!("status: {}", c.status),
+                    format!("image: {}", c.image),
+                    format!("ports: {}", blank_if_empty(&c.ports)),
+                    format!("runtime: {}", self.data.docker.source),
+                ];
+                if let Some(created) = c.created {
+                    lines.push(format!(
+                        "created: {}",
+                        created.format("%Y-%m-%d %H:%M:%S UTC")
+                    ));
+                }
+                if let Some(health) = &c.health {
+                    lines.push(format!("health: {health}"));
+                }
+                if let Some(code) = c.exit_code {
+                    lines.push(format!("exit code: {code}"));
+                }
+                if let Some(stats) = &c.stats {
+                    lines.push(format!(
+                        "cpu: {}",
+                        stats
+                            .cpu_pct
+                            .map_or_else(|| "sampling...".to_string(), |v| format!("{v:.1}%"))
+                    ));
+                    lines.push(format!(
+                        "memory: {:.1} MB / {:.1} MB",
+                        stats.mem_used_bytes as f64 / 1_048_576.0,
+                        stats.mem_limit_bytes as f64 / 1_048_576.0
+                    ));
+                    lines.push(format!(
+                        "net rx/tx: {:.1} MB / {:.1} MB",
+                        stats.net_rx_bytes as f64 / 1_048_576.0,
+                        stats.net_tx_bytes as f64 / 1_048_576.0
+                    ));
+                    lines.push(format!(
+                        "block read/write: {:.1} MB / {:.1} MB",
+                        stats.blk_read_bytes as f64 / 1_048_576.0,
+                        stats.blk_write_bytes as f64 / 1_048_576.0
+                    ));
+                }
+                if let Some(history) = self.container_history.get(&c.id) {
+                    lines.push(format!("cpu history: {}", text_sparkline(&history.cpu)));
+                    lines.push(format!("mem history: {}", text_sparkline(&history.mem)));
+                }
+                let events = self
+                    .docker_events
+                    .iter()
+                    .rev()
+                    .filter(|e| e.id == c.id)
+                    .take(6)
+                    .collect::<Vec<_>>();
+                if !events.is_empty() {
+                    lines.push("recent events:".to_string());
+                    lines.extend(events.iter().map(|e| {
+                        let detail = e
+                            .detail
+                            .as_deref()
+                            .map_or(String::new(), |d| format!(" ({d})"));
+                        format!("  {} {}{detail}", e.time.format("%m-%d %H:%M:%S"), e.action)
+                    }));
+                }
+                lines.push(format!(
+                    "networks: {}",
+                    blank_if_empty(&c.networks.join(", "))
+                ));
+                if !c.mounts.is_empty() {
+                    lines.push("mounts:".to_string());
+                    lines.extend(c.mounts.iter().map(|m| format!("  {m}")));
+                }
+                if !c.labels.is_empty() {
+                    lines.push("labels:".to_string());
+                    lines.extend(c.labels.iter().map(|(k, v)| format!("  {k}={v}")));
+                }
+
                 DetailModal {
                     title: format!("Container {}", c.name),
-                    lines: vec![
-                        format!("id: {}", blank_if_empty(&c.id)),
-                        format!("name: {}", c.name),
-                        format!("status: {}", c.status),
-                        format!("image: {}", c.image),
-                        format!("ports: {}", blank_if_empty(&c.ports)),
-                    ],
+                    lines,
                 }
             }
-            Pane::Aws => {
-                let Some(i) = self.data.aws.items.get(selected_idx) else {
-                    return;
-                };
-
-                DetailModal {
-                    title: format!("EC2 {}", i.id),
-                    lines: vec![
+            Pane::Aws => match self.aws_rows().into_iter().nth(selected_idx) {
+                Some(AwsRow::Instance(i)) => {
+                    let mut lines = vec![
                         format!("name: {}", i.name),
                         format!("state: {}", i.state),
-                        format!("type: {}", i.instance_type),
+                        format!("type: {} ({})", i.instance_type, i.platform),
+                        format!("ami: {}", i.image_id),
                         format!("az: {}", i.az),
+                        format!(
+                            "vpc / subnet: {} / {}",
+                            i.vpc_id.as_deref().unwrap_or("none"),
+                            i.subnet_id.as_deref().unwrap_or("none")
+                        ),
                         format!("public ip: {}", i.public_ip),
                         format!("private ip: {}", i.private_ip),
-                        format!("source: {}", self.data.aws.source),
+                    ];
+                    // Connect commands early so a short terminal doesn't clip them
+                    if let Some(ssh) = i.ssh_command() {
+                        lines.push(format!("ssh: {ssh}"));
+                    }
+                    lines.push(format!("ssm: {}", i.ssm_command()));
+                    lines.extend([
+                        format!(
+                            "security groups: {}",
+                            blank_if_empty(&i.security_groups.join(", "))
+                        ),
+                        format!("key pair: {}", i.key_name.as_deref().unwrap_or("none")),
+                        format!(
+                            "iam profile: {}",
+                            i.iam_profile.as_deref().unwrap_or("none")
+                        ),
+                    ]);
+                    if let Some(launched) = i.launch_time {
+                        let mut line =
+                            format!("launched: {}", launched.format("%Y-%m-%d %H:%M:%S UTC"));
+                        if i.state == "running" {
+                            let up = (Utc::now() - launched).num_seconds().max(0) as u64;
+                            line.push_str(&format!(" (up {})", format_duration_short(up)));
+                        }
+                        lines.push(line);
+                    }
+                    lines.push(format!("profile: {}", i.profile));
+                    lines.push(format!("region: {}", i.region));
+                    lines.push(format!("source: {}", self.data.aws.source));
+                    if !i.tags.is_empty() {
+                        lines.push("tags:".to_string());
+                        lines.extend(i.tags.iter().map(|(k, v)| format!("  {k}={v}")));
+                    }
+                    DetailModal {
+                        title: format!("EC2 {}", i.id),
+                        lines,
+                    }
+                }
+                Some(AwsRow::Service(svc)) => DetailModal {
+                    title: format!("ECS {}", svc.name),
+                    lines: vec![
+                        format!("cluster: {}", svc.cluster),
+                        format!("status: {} ({})", svc.status, svc.health()),
+                        format!(
+                            "tasks: {} running / {} desired / {} pending",
+                            svc.running, svc.desired, svc.pending
+                        ),
+                        format!("launch type: {}", svc.launch_type),
+                        format!("profile: {}", svc.profile),
+                        format!("region: {}", svc.region),
+                    ],
+                },
+                Some(AwsRow::Function(f)) => DetailModal {
+                    title: format!("Lambda {}", f.name),
+                    lines: vec![
+                        format!("runtime: {}", f.runtime),
+                        format!("last modified: {}", f.last_modified),
+                        format!("memory: {} MB", f.memory_mb),
+                        format!("timeout: {}s", f.timeout_secs),
+                        format!("profile: {}", f.profile),
+                        format!("region: {}", f.region),
                     ],
+                },
+                Some(AwsRow::Database(d)) => DetailModal {
+                    title: format!("RDS {}", d.id),
+                    lines: vec![
+                        format!("status: {}", d.status),
+                        format!("engine: {} {}", d.engine, d.engine_version),
+                        format!("class: {}", d.class),
+                        format!("endpoint: {}", d.endpoint),
+                        format!("multi-az: {}", if d.multi_az { "yes" } else { "no" }),
+                        format!("profile: {}", d.profile),
+                        format!("region: {}", d.region),
+                    ],
+                },
+                Some(AwsRow::Alarm(a)) => DetailModal {
+                    title: format!("Alarm {}", a.name),
+                    lines: vec![
+                        format!("metric: {}/{}", a.namespace, a.metric),
+                        format!("in ALARM since: {}", a.since),
+                        format!("reason: {}", a.reason),
+                        format!("profile: {}", a.profile),
+                        format!("region: {}", a.region),
+                    ],
+                },
+                Some(AwsRow::Group(group)) => {
+                    let target = group.target;
+                    let mut lines = vec![
+                        format!(
+                            "account: {}",
+                            target.account.as_deref().unwrap_or("unknown")
+                        ),
+                        format!("profile: {}", target.profile),
+                        format!("region: {}", target.region),
+                    ];
+                    lines.extend(
+                        group
+                            .states
+                            .iter()
+                            .map(|(state, count)| format!("{state}: {count}")),
+                    );
+                    for (kind, err) in &target.errors {
+                        lines.push(format!("{} error: {err}", kind.label().to_lowercase()));
+                    }
+                    DetailModal {
+                        title: format!("AWS {}", group.label()),
+                        lines,
+                    }
                 }
-            }
+                None => return,
+            },
             Pane::Plugins => {
                 let Some(p) = self.data.plugins.get(selected_idx) else {
                     return;
@@ -371,12 +1241,363 @@ impl App {
                     lines,
                 }
             }
+            Pane::Ports => {
+                let Some(s) = self.visible_ports().get(selected_idx).copied() else {
+                    return;
+                };
+
+                let mut lines = vec![
+                    format!("protocol: {}", s.protocol),
+                    format!("address: {}", s.local_addr),
+                    format!("port: {}", s.port),
+                    format!(
+                        "pid: {}",
+                        s.pid.map_or_else(|| "n/a".to_string(), |p| p.to_string())
+                    ),
+                    format!("process: {}", s.process),
+                ];
+                if let Some(p) = &s.process_stat {
+                    lines.push(format!("command: {}", blank_if_empty(&p.command)));
+                    lines.push(format!("cpu: {:.1}%", p.cpu_pct));
+                    lines.push(format!("memory: {:.1} MB", p.mem_mb));
+                    lines.push(String::new());
+                    lines.push("[p] jump to process".to_string());
+                }
+
+                DetailModal {
+                    title: format!("Port {}/{}", s.port, s.protocol),
+                    lines,
+                }
+            }
+            Pane::Services => {
+                let Some(u) = self.data.services.items.get(selected_idx) else {
+                    return;
+                };
+
+                DetailModal {
+                    title: format!("Unit {}", u.name),
+                    lines: vec![
+                        format!("unit: {}", u.name),
+                        format!("description: {}", blank_if_empty(&u.description)),
+                        format!("scope: {}", if u.user { "user" } else { "system" }),
+                        format!("load: {}", u.load),
+                        format!("active: {}", u.active),
+                        format!("sub: {}", u.sub),
+                        String::new(),
+                        "[s] start  [x] stop  [R] restart".to_string(),
+                    ],
+                }
+            }
             _ => return,
         };
 
         self.detail_modal = Some(detail);
     }
 
+    /// Ask for confirmation before running `verb` on the selected systemd unit
+    pub fn request_unit_action(&mut self, verb: UnitVerb) {
+        let Some(idx) = self.current_list_cursor(Pane::Services) else {
+            self.set_status("no unit selected");
+            return;
+        };
+        let Some(unit) = self.data.services.items.get(idx) else {
+            return;
+        };
+
+        let action = Action::Systemd {
+            unit: unit.name.clone(),
+            user: unit.user,
+            verb,
+        };
+        self.confirm = Some(ConfirmModal {
+            title: format!("{} unit", verb.as_str()),
+            lines: vec![
+                format!("{}?", action.describe()),
+                format!("currently: {} ({})", unit.active, unit.sub),
+            ],
+            action,
+        });
+    }
+
+    /// Ask to run a lifecycle verb on the selected container; `Pause` toggles to unpause when paused
+    pub fn request_container_action(&mut self, verb: ContainerVerb) {
+        if self.docker_storage_view {
+            if verb == ContainerVerb::Remove {
+                self.request_prune();
+            } else {
+                self.set_status("container actions live in the containers view (i)");
+            }
+            return;
+        }
+        if let Some(DockerRow::Project(project)) = self.selected_docker_row() {
+            self.request_project_action(project, verb);
+            return;
+        }
+        let Some(c) = self.selected_container() else {
+            self.set_status("no container selected");
+            return;
+        };
+
+        let verb = match verb {
+            ContainerVerb::Pause if c.state == "paused" => ContainerVerb::Unpause,
+            other => other,
+        };
+        let action = Action::Container {
+            docker: self.docker_cfg.clone(),
+            id: c.id.clone(),
+            name: c.name.clone(),
+            verb,
+        };
+        let mut lines = vec![
+            format!("{}?", action.describe()),
+            format!("currently: {} ({})", c.state, c.status),
+        ];
+        if verb == ContainerVerb::Remove {
+            lines.push("the container and its writable layer will be deleted".to_string());
+        }
+        self.confirm = Some(ConfirmModal {
+            title: format!("{} container", verb.as_str()),
+            lines,
+            action,
+        });
+    }
+
+    /// Ask to start/stop/reboot/terminate the selected EC2 instance; terminate needs its name retyped
+    pub fn request_instance_action(&mut self, verb: Ec2Verb) {
+        if self.aws_view != AwsResource::Ec2 {
+            self.set_status("instance actions live in the EC2 view (v)");
+            return;
+        }
+        let Some(i) = self.selected_aws_instance() else {
+            self.set_status("no instance selected");
+            return;
+        };
+        let allowed = match verb {
+            Ec2Verb::Start => i.state == "stopped",
+            Ec2Verb::Stop | Ec2Verb::Reboot => i.state == "running",
+            Ec2Verb::Terminate => !matches!(i.state.as_str(), "shutting-down" | "terminated"),
+        };
+        if !allowed {
+            self.set_status(format!(
+                "{} is {}, cannot {}",
+                i.name,
+                i.state,
+                verb.as_str()
+            ));
+            return;
+        }
+
+        let action = Action::Ec2 {
+            aws: self.aws_cfg.clone(),
+            profile: i.profile.clone(),
+            region: i.region.clone(),
+            id: i.id.clone(),
+            name: i.name.clone(),
+            verb,
+        };
+        let mut lines = vec![
+            format!("{}?", action.describe()),
+            format!("currently: {} ({}, {})", i.state, i.instance_type, i.az),
+            format!("{} in {}", i.profile, i.region),
+        ];
+        if let Some(phrase) = action.confirm_phrase() {
+            lines.push("the instance and its instance-store data will be deleted".to_string());
+            lines.push(format!("type {phrase} and press Enter to confirm"));
+        }
+        self.confirm = Some(ConfirmModal {
+            title: format!("{} instance", verb.as_str()),
+            lines,
+            action,
+        });
+    }
+
+    fn selected_aws_instance(&self) -> Option<AwsInstance> {
+        let idx = self.current_list_cursor(Pane::Aws)?;
+        match self.aws_rows().into_iter().nth(idx)? {
+            AwsRow::Instance(instance) => Some(instance.clone()),
+            _ => None,
+        }
+    }
+
+    /// Start/stop/restart on a project header map to `compose up -d` / `down` / `restart`
+    fn request_project_action(&mut self, project: ComposeProject, verb: ContainerVerb) {
+        let verb = match verb {
+            ContainerVerb::Start => ComposeVerb::Up,
+            ContainerVerb::Stop => ComposeVerb::Down,
+            ContainerVerb::Restart => ComposeVerb::Restart,
+            _ => {
+                self.set_status("projects support start (up), stop (down) and restart");
+                return;
+            }
+        };
+        let action = Action::Compose {
+            docker: self.docker_cfg.clone(),
+            project: project.name.clone(),
+            working_dir: project.working_dir.as_ref().map(PathBuf::from),
+            config_files: project.config_files.clone(),
+            verb,
+        };
+        let mut lines = vec![
+            format!("{}?", action.describe()),
+            format!(
+                "{}/{} running, working dir: {}",
+                project.running,
+                project.total,
+                project.working_dir.as_deref().unwrap_or("n/a")
+            ),
+        ];
+        if verb == ComposeVerb::Down {
+            lines.push("containers and networks of the project will be removed".to_string());
+        }
+        self.confirm = Some(ConfirmModal {
+            title: format!("compose {}", verb.as_str()),
+            lines,
+            action,
+        });
+    }
+
+    /// Hand the terminal to `<runtime> exec -it <id> <shell>` for the selected container
+    pub fn exec_into_container(&mut self) {
+        let Some(c) = self.selected_container() else {
+            self.set_status("no container selected");
+            return;
+        };
+        if c.state != "running" {
+            self.set_status(format!("{} is {}, start it first", c.name, c.state));
+            return;
+        }
+
+        let program = Runtime::detect(&self.docker_cfg).map_or("docker", |r| r.name());
+        let mut args = vec!["exec".to_string(), "-it".to_string(), c.id.clone()];
+        args.extend(self.docker_cfg.shell.split_whitespace().map(str::to_string));
+        self.detail_modal = None;
+        self.pending_terminal = Some(TerminalCommand {
+            label: format!("shell in {}", c.name),
+            program: program.to_string(),
+            args,
+        });
+    }
+
+    /// Open the full-screen log viewer for the selected container
+    pub fn open_container_logs(&mut self) {
+        let Some(c) = self.selected_container() else {
+            self.set_status("no container selected");
+            return;
+        };
+
+        self.detail_modal = None;
+        self.log_viewer = Some(LogViewer {
+            container_id: c.id,
+            container_name: c.name,
+            buffer: LogBuffer::new(self.logs.capacity().max(5000)),
+            window: 0,
+            search: String::new(),
+            search_mode: false,
+            stream_tag: String::new(),
+        });
+        self.restart_viewer_stream();
+    }
+
+    pub fn close_log_viewer(&mut self) {
+        if self.log_viewer.take().is_some() {
+            self.pending_stream = Some(StreamRequest::Stop);
+        }
+    }
+
+    /// Step to the next since/tail preset and reload the stream
+    pub fn cycle_log_window(&mut self) {
+        if let Some(viewer) = &mut self.log_viewer {
+            viewer.window += 1;
+            self.restart_viewer_stream();
+        }
+    }
+
+    fn restart_viewer_stream(&mut self) {
+        let Some(viewer) = &mut self.log_viewer else {
+            return;
+        };
+        self.stream_seq += 1;
+        viewer.stream_tag = format!("{}#{}", viewer.container_id, self.stream_seq);
+        viewer.buffer.clear();
+        viewer.buffer.follow();
+        let window = viewer.window();
+        self.pending_stream = Some(StreamRequest::ContainerLogs {
+            docker: self.docker_cfg.clone(),
+            id: viewer.container_id.clone(),
+            tail: window.tail,
+            since_secs: window.since_secs,
+            tag: viewer.stream_tag.clone(),
+        });
+    }
+
+    pub fn push_viewer_line(&mut self, line: StreamLine) {
+        let Some(viewer) = &mut self.log_viewer else {
+            return;
+        };
+        // Lines from a stream that was replaced or closed are dropped
+        if line.source != viewer.stream_tag {
+            return;
+        }
+        let text = short_timestamp(&line.text);
+        let severity = self.log_rules.classify(&text);
+        viewer.buffer.push(
+            LogLine {
+                source: viewer.container_name.clone(),
+                text,
+                severity,
+            },
+            true,
+        );
+    }
+
+    pub fn scroll_viewer(&mut self, delta: isize) {
+        if let Some(viewer) = &mut self.log_viewer {
+            let len = viewer.buffer.iter().count();
+            viewer.buffer.scroll_by(delta, len);
+        }
+    }
+
+    pub fn search_viewer(&mut self, older: bool) {
+        let Some(viewer) = &mut self.log_viewer else {
+            return;
+        };
+        let needle = viewer.search.trim().to_ascii_lowercase();
+        if needle.is_empty() {
+            return;
+        }
+        let lines = viewer.buffer.iter().collect::<Vec<_>>();
+        match find_match(&lines, &viewer.buffer, &needle, older) {
+            Some(scroll) => viewer.buffer.jump_to(scroll),
+            None => self.set_status(format!("no match for '{needle}'")),
+        }
+    }
+
+    pub fn cancel_confirm(&mut self) {
+        self.confirm = None;
+        self.confirm_input.clear();
+    }
+
+    /// Show what a just-confirmed action is doing before its outcome and the next refresh arrive
+    pub fn begin_action(&mut self, action: &Action) {
+        self.set_status(format!("{}...", action.describe()));
+        if let Action::Ec2 {
+            profile,
+            region,
+            id,
+            verb,
+            ..
+        } = action
+            && let Some(instance) = self
+                .data
+                .aws
+                .items
+                .iter_mut()
+                .find(|i| i.id == *id && i.profile == *profile && i.region == *region)
+        {
+            instance.state = verb.transitional_state().to_string();
+        }
+    }
+
     pub fn close_details(&mut self) {
         self.detail_modal = None;
     }
@@ -397,19 +1618,26 @@ impl App {
     fn normalize_cursors(&mut self) {
         self.cursors.system =
             clamp_cursor(self.cursors.system, self.data.system.top_processes.len());
-        self.cursors.prs = clamp_cursor(self.cursors.prs, self.data.prs.open.len());
-        self.cursors.docker = clamp_cursor(self.cursors.docker, self.data.docker.running.len());
-        self.cursors.aws = clamp_cursor(self.cursors.aws, self.data.aws.instances.len());
+        if self.pr_view >= self.data.prs.views.len() {
+            self.pr_view = 0;
+        }
+        self.cursors.prs = clamp_cursor(self.cursors.prs, self.pr_rows().len());
+        self.cursors.docker = clamp_cursor(self.cursors.docker, self.docker_row_count());
+        self.cursors.aws = clamp_cursor(self.cursors.aws, self.aws_rows().len());
         self.cursors.plugins = clamp_cursor(self.cursors.plugins, self.data.plugins.len());
+        self.cursors.ports = clamp_cursor(self.cursors.ports, self.visible_ports().len());
+        self.cursors.services = clamp_cursor(self.cursors.services, self.data.services.items.len());
     }
 
     fn list_len_for(&self, pane: Pane) -> usize {
         match pane {
             Pane::System => self.data.system.top_processes.len(),
-            Pane::Prs => self.data.prs.open.len(),
-            Pane::Docker => self.data.docker.running.len(),
-            Pane::Aws => self.data.aws.instances.len(),
+            Pane::Prs => self.pr_rows().len(),
+            Pane::Docker => self.docker_row_count(),
+            Pane::Aws => self.aws_rows().len(),
             Pane::Plugins => self.data.plugins.len(),
+            Pane::Ports => self.visible_ports().len(),
+            Pane::Services => self.data.services.items.len(),
             _ => 0,
         }
     }
@@ -421,9 +1649,99 @@ impl App {
             Pane::Docker => &mut self.cursors.docker,
             Pane::Aws => &mut self.cursors.aws,
             Pane::Plugins => &mut self.cursors.plugins,
+            Pane::Ports => &mut self.cursors.ports,
+            Pane::Services => &mut self.cursors.services,
             _ => &mut self.cursors.prs,
         }
     }
+
+    fn filter_mut_for(&mut self, pane: Pane) -> Option<&mut String> {
+        match pane {
+            Pane::Aws => Some(&mut self.filters.aws),
+            Pane::Ports => Some(&mut self.filters.ports),
+            Pane::Logs => Some(&mut self.filters.logs),
+            _ => None,
+        }
+    }
+}
+
+fn process_detail(p: &ProcessStat) -> DetailModal {
+    DetailModal {
+        title: format!("Process {} ({})", p.name, p.pid),
+        lines: vec![
+            format!("pid: {}", p.pid),
+            format!("name: {}", p.name),
+            format!(
+                "command: {}",
+                if p.command.is_empty() {
+                    "n/a"
+                } else {
+                    &p.command
+                }
+            ),
+            format!("cpu: {:.1}%", p.cpu_pct),
+            format!("memory: {:.1} MB", p.mem_mb),
+            format!("runtime: {}", format_duration_short(p.runtime_secs)),
+            format!("io read: {:.1} MB", p.read_mb),
+            format!("io write: {:.1} MB", p.write_mb),
+        ],
+    }
+}
+
+fn storage_detail(row: StorageRow<'_>) -> DetailModal {
+    let mb = |bytes: u64| format!("{:.1} MB", bytes as f64 / 1_000_000.0);
+    match row {
+        StorageRow::Usage(usage) => DetailModal {
+            title: format!("Disk usage: {}", usage.kind),
+            lines: vec![
+                format!("total: {}", usage.total),
+                format!("active: {}", usage.active),
+                format!("size: {}", mb(usage.size_bytes)),
+                format!("reclaimable: {}", mb(usage.reclaimable_bytes)),
+                String::new(),
+                format!("D prunes unused {}", usage.kind),
+            ],
+        },
+        StorageRow::Image(image) => {
+            let mut lines = vec![
+                format!("id: {}", image.id),
+                format!(
+                    "tags: {}",
+                    if image.dangling {
+                        "<none> (dangling)".to_string()
+                    } else {
+                        image.tags.join(", ")
+                    }
+                ),
+                format!("size: {}", mb(image.size_bytes)),
+                format!("shared: {}", mb(image.shared_bytes)),
+                format!("containers: {}", image.containers),
+            ];
+            if let Some(created) = image.created {
+                lines.push(format!("created: {}", created.format("%Y-%m-%d %H:%M UTC")));
+            }
+            DetailModal {
+                title: format!("Image {}", image.tags.first().unwrap_or(&image.id)),
+                lines,
+            }
+        }
+        StorageRow::Volume(volume) => DetailModal {
+            title: format!("Volume {}", volume.name),
+            lines: vec![
+                format!("driver: {}", volume.driver),
+                format!("mountpoint: {}", volume.mountpoint),
+                format!("size: {}", volume.size_bytes.map_or("n/a".to_string(), mb)),
+                format!(
+                    "in use: {}",
+                    if volume.ref_count > 0 {
+                        format!("yes ({} containers)", volume.ref_count)
+                    } else {
+                        "no".to_string()
+                    }
+                ),
+            ],
+        },
+    }
 }
 
 fn blank_if_empty(s: &str) -> &str {
@@ -436,9 +1754,9 @@ fn clamp_cursor(current: usize, len: usize) -> usize {
 
 fn adjust_three_cols(cols: &mut [u16; 3], selected: Pane, step: i16) {
     let idx = match selected {
-        Pane::Git | Pane::Docker => 0,
-        Pane::System | Pane::Aws => 1,
-        Pane::Prs | Pane::Plugins => 2,
+        Pane::Git | Pane::Docker | Pane::Ports => 0,
+        Pane::System | Pane::Aws | Pane::Services => 1,
+        Pane::Prs | Pane::Plugins | Pane::Logs => 2,
     };
 
     let target = cols[idx] as i16 + step;
@@ -464,6 +1782,16 @@ fn adjust_three_cols(cols: &mut [u16; 3], selected: Pane, step: i16) {
     }
 }
 
+/// Block-character sparkline scaled to the series maximum, for text-only modals
+fn text_sparkline(values: &[u64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().copied().max().unwrap_or(0).max(1);
+    values
+        .iter()
+        .map(|v| BARS[(*v * 7 / max) as usize])
+        .collect()
+}
+
 fn push_bounded(buf: &mut Vec<u64>, value: u64, max: usize) {
     buf.push(value);
     if buf.len() > max {
@@ -472,6 +1800,15 @@ fn push_bounded(buf: &mut Vec<u64>, value: u64, max: usize) {
     }
 }
 
+fn review_rank(pr: &PrItem) -> u8 {
+    match pr.review_decision.as_deref() {
+        Some("review required") => 0,
+        Some("changes requested") => 1,
+        Some("approved") => 3,
+        _ => 2,
+    }
+}
+
 fn parse_layout_mode(raw: &str) -> SystemLayoutMode {
     match raw.trim().to_ascii_lowercase().as_str() {
         "compact" => SystemLayoutMode::Compact,
@@ -1,3 +1,10 @@
+use crate::actions::Action;
+use crate::collectors::{
+    AwsInstance, AwsTarget, CloudWatchAlarm, DiskUsage, DockerContainer, DockerImage, DockerVolume,
+    EcsService, LambdaFunction, RdsInstance,
+};
+use crate::config::DockerConfig;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Pane {
     Git,
@@ -6,22 +13,35 @@ pub enum Pane {
     Docker,
     Aws,
     Plugins,
+    Ports,
+    Services,
+    Logs,
 }
 
 impl Pane {
-    pub const ALL: [Pane; 6] = [
+    pub const ALL: [Pane; 9] = [
         Pane::Git,
         Pane::System,
         Pane::Prs,
         Pane::Docker,
         Pane::Aws,
         Pane::Plugins,
+        Pane::Ports,
+        Pane::Services,
+        Pane::Logs,
     ];
+
+    /// Panes whose list can be narrowed (or, for Logs, searched) with `/`
+    pub fn supports_filter(self) -> bool {
+        matches!(self, Pane::Aws | Pane::Ports | Pane::Logs)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct LayoutState {
     pub top_height_pct: u16,
+    pub extra_height_pct: u16,
+    pub extra_cols_pct: [u16; 3],
     pub top_cols_pct: [u16; 3],
     pub bottom_cols_pct: [u16; 3],
 }
@@ -29,7 +49,9 @@ pub struct LayoutState {
 impl Default for LayoutState {
     fn default() -> Self {
         Self {
-            top_height_pct: 56,
+            top_height_pct: 44,
+            extra_height_pct: 26,
+            extra_cols_pct: [30, 30, 40],
             top_cols_pct: [33, 33, 34],
             bottom_cols_pct: [34, 33, 33],
         }
@@ -43,6 +65,15 @@ pub struct ListCursorState {
     pub docker: usize,
     pub aws: usize,
     pub plugins: usize,
+    pub ports: usize,
+    pub services: usize,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListFilterState {
+    pub aws: String,
+    pub ports: String,
+    pub logs: String,
 }
 
 #[derive(Debug, Clone)]
@@ -51,6 +82,14 @@ pub struct DetailModal {
     pub lines: Vec<String>,
 }
 
+/// Pending action awaiting `y`/`n` from the user
+#[derive(Debug, Clone)]
+pub struct ConfirmModal {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub action: Action,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum NavDir {
     Left,
@@ -64,11 +103,201 @@ pub enum PaletteCommand {
     Refresh,
     ReloadConfig,
     ToggleCompact,
+    ToggleCoreView,
     Focus(Pane),
     Quit,
     Help,
 }
 
+/// Background stream the UI loop should start or stop on the app's behalf
+#[derive(Debug, Clone)]
+pub enum StreamRequest {
+    ContainerLogs {
+        docker: DockerConfig,
+        id: String,
+        tail: Option<u32>,
+        since_secs: Option<u64>,
+        tag: String,
+    },
+    Stop,
+}
+
+/// Interactive command the UI loop runs with the terminal handed over, restoring the TUI after
+#[derive(Debug, Clone)]
+pub struct TerminalCommand {
+    pub label: String,
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+/// Which containers the Docker pane lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DockerFilter {
+    #[default]
+    All,
+    Running,
+    Unhealthy,
+}
+
+impl DockerFilter {
+    pub fn next(self) -> Self {
+        match self {
+            DockerFilter::All => DockerFilter::Running,
+            DockerFilter::Running => DockerFilter::Unhealthy,
+            DockerFilter::Unhealthy => DockerFilter::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DockerFilter::All => "all",
+            DockerFilter::Running => "running",
+            DockerFilter::Unhealthy => "unhealthy",
+        }
+    }
+}
+
+/// Sort order for the Docker pane; resource sorts put the heaviest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DockerSort {
+    #[default]
+    Default,
+    Cpu,
+    Memory,
+    Network,
+    BlockIo,
+}
+
+impl DockerSort {
+    pub fn next(self) -> Self {
+        match self {
+            DockerSort::Default => DockerSort::Cpu,
+            DockerSort::Cpu => DockerSort::Memory,
+            DockerSort::Memory => DockerSort::Network,
+            DockerSort::Network => DockerSort::BlockIo,
+            DockerSort::BlockIo => DockerSort::Default,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DockerSort::Default => "created",
+            DockerSort::Cpu => "cpu",
+            DockerSort::Memory => "mem",
+            DockerSort::Network => "net",
+            DockerSort::BlockIo => "blk",
+        }
+    }
+}
+
+/// Sort order for the PR pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrSort {
+    /// Most recently updated first
+    #[default]
+    Updated,
+    /// Waiting on review first, then changes requested, undecided, approved
+    Review,
+    /// Oldest first
+    Age,
+}
+
+impl PrSort {
+    pub fn next(self) -> Self {
+        match self {
+            PrSort::Updated => PrSort::Review,
+            PrSort::Review => PrSort::Age,
+            PrSort::Age => PrSort::Updated,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PrSort::Updated => "updated",
+            PrSort::Review => "review",
+            PrSort::Age => "age",
+        }
+    }
+}
+
+/// Aggregate view of a Compose project's containers in the Docker pane
+#[derive(Debug, Clone, Default)]
+pub struct ComposeProject {
+    pub name: String,
+    pub working_dir: Option<String>,
+    pub config_files: Vec<String>,
+    pub total: usize,
+    pub running: usize,
+    pub failing: usize,
+    pub collapsed: bool,
+}
+
+/// One line of the Docker pane: a project header or a container beneath it
+#[derive(Debug, Clone)]
+pub enum DockerRow<'a> {
+    Project(ComposeProject),
+    Container {
+        container: &'a DockerContainer,
+        grouped: bool,
+    },
+}
+
+/// Header for one account/region in the AWS pane, with item counts per state of the current view
+#[derive(Debug, Clone)]
+pub struct AwsGroup<'a> {
+    pub target: &'a AwsTarget,
+    /// Why the current view could not be listed for this target
+    pub error: Option<&'a str>,
+    pub states: Vec<(&'a str, usize)>,
+}
+
+impl AwsGroup<'_> {
+    /// Account id when known, otherwise the profile it was listed with
+    pub fn label(&self) -> String {
+        match &self.target.account {
+            Some(account) => format!("{account}/{}", self.target.region),
+            None => format!("{}/{}", self.target.profile, self.target.region),
+        }
+    }
+}
+
+/// One line of the AWS pane: an account/region header or an item of the current view beneath it
+#[derive(Debug, Clone)]
+pub enum AwsRow<'a> {
+    Group(AwsGroup<'a>),
+    Instance(&'a AwsInstance),
+    Service(&'a EcsService),
+    Function(&'a LambdaFunction),
+    Database(&'a RdsInstance),
+    Alarm(&'a CloudWatchAlarm),
+}
+
+/// One line of the Docker storage view: a `system df` summary row, an image or a volume
+#[derive(Debug, Clone, Copy)]
+pub enum StorageRow<'a> {
+    Usage(&'a DiskUsage),
+    Image(&'a DockerImage),
+    Volume(&'a DockerVolume),
+}
+
+impl StorageRow<'_> {
+    /// What `prune` removes when this row is selected
+    pub fn prune_kind(&self) -> &str {
+        match self {
+            StorageRow::Usage(usage) => &usage.kind,
+            StorageRow::Image(_) => "images",
+            StorageRow::Volume(_) => "volumes",
+        }
+    }
+}
+
+/// Recent samples for one container, shown as sparklines in its detail modal
+#[derive(Debug, Clone, Default)]
+pub struct ContainerHistory {
+    pub cpu: Vec<u64>,
+    pub mem: Vec<u64>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SystemLayoutMode {
     Auto,
@@ -107,6 +336,9 @@ pub fn parse_pane(s: &str) -> Option<Pane> {
         "4" | "docker" => Some(Pane::Docker),
         "5" | "aws" => Some(Pane::Aws),
         "6" | "plugins" | "plugin" => Some(Pane::Plugins),
+        "7" | "ports" | "port" => Some(Pane::Ports),
+        "8" | "services" | "systemd" => Some(Pane::Services),
+        "9" | "logs" | "log" => Some(Pane::Logs),
         _ => None,
     }
 }
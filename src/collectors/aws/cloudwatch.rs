@@ -0,0 +1,42 @@
+use roxmltree::Document;
+
+use super::{AwsClient, child_text};
+use crate::collectors::types::CloudWatchAlarm;
+
+const CLOUDWATCH_API_VERSION: &str = "2010-08-01";
+
+/// Metric alarms currently in `ALARM`, following `NextToken`
+pub async fn alarms_firing(client: &AwsClient) -> Result<Vec<CloudWatchAlarm>, String> {
+    let params = [
+        ("Action", "DescribeAlarms"),
+        ("Version", CLOUDWATCH_API_VERSION),
+        ("StateValue", "ALARM"),
+        ("MaxRecords", "100"),
+    ]
+    .map(|(k, v)| (k.to_string(), v.to_string()));
+    let pages = client
+        .query_pages("monitoring", &params, "NextToken", "NextToken")
+        .await?;
+
+    let mut alarms = Vec::new();
+    for body in pages {
+        let doc = Document::parse(&body).map_err(|e| format!("AWS parse error: {e}"))?;
+        alarms.extend(
+            doc.descendants()
+                .filter(|n| n.has_tag_name("MetricAlarms"))
+                .flat_map(|set| set.children().filter(|n| n.has_tag_name("member")))
+                .map(|node| {
+                    let text = |name: &str| child_text(node, name).unwrap_or("").to_string();
+                    CloudWatchAlarm {
+                        name: text("AlarmName"),
+                        reason: text("StateReason"),
+                        since: text("StateUpdatedTimestamp"),
+                        namespace: text("Namespace"),
+                        metric: text("MetricName"),
+                        ..CloudWatchAlarm::default()
+                    }
+                }),
+        );
+    }
+    Ok(alarms)
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+
+use crate::collectors::run_cmd;
+
+/// Keys used to sign requests
+#[derive(Debug, Clone)]
+pub struct Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+}
+
+/// Credentials for one profile plus the region it defaults to
+#[derive(Debug, Clone)]
+pub struct ResolvedProfile {
+    pub credentials: Credentials,
+    pub region: Option<String>,
+    /// `env` or `profile <name>`, shown as the pane's source
+    pub source: String,
+}
+
+type Section = HashMap<String, String>;
+type ProcessCache = HashMap<String, (Credentials, DateTime<Utc>)>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ProcessOutput {
+    access_key_id: String,
+    secret_access_key: String,
+    #[serde(default)]
+    session_token: Option<String>,
+    #[serde(default)]
+    expiration: Option<DateTime<Utc>>,
+}
+
+/// `credential_process` results by command, reused until shortly before they expire
+fn process_cache() -> &'static Mutex<ProcessCache> {
+    static CACHE: OnceLock<Mutex<ProcessCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Resolve credentials like the AWS CLI: an explicit `[aws] profile` wins, then
+/// `AWS_ACCESS_KEY_ID`/`AWS_SECRET_ACCESS_KEY`, then `AWS_PROFILE` or `default` from
+/// `~/.aws/credentials` and `~/.aws/config`
+pub async fn resolve(profile: Option<&str>) -> Result<ResolvedProfile, String> {
+    let explicit = profile.map(str::to_string).filter(|p| !p.trim().is_empty());
+    let name = explicit
+        .clone()
+        .or_else(|| non_empty_env("AWS_PROFILE"))
+        .unwrap_or_else(|| "default".to_string());
+
+    let config = read_ini(config_path());
+    let credentials = read_ini(credentials_path());
+    let empty = Section::new();
+    let config_section = config
+        .get(&format!("profile {name}"))
+        .or_else(|| config.get(&name))
+        .unwrap_or(&empty);
+    let region = config_section.get("region").cloned();
+
+    if explicit.is_none()
+        && let Some(creds) = env_credentials()
+    {
+        return Ok(ResolvedProfile {
+            credentials: creds,
+            region,
+            source: "env".to_string(),
+        });
+    }
+
+    let source = format!("profile {name}");
+    for section in [credentials.get(&name), Some(config_section)]
+        .into_iter()
+        .flatten()
+    {
+        if let Some(creds) = static_credentials(section) {
+            return Ok(ResolvedProfile {
+                credentials: creds,
+                region,
+                source,
+            });
+        }
+    }
+
+    if let Some(command) = config_section.get("credential_process") {
+        return Ok(ResolvedProfile {
+            credentials: run_credential_process(command).await?,
+            region,
+            source,
+        });
+    }
+
+    if config_section.contains_key("sso_session")
+        || config_section.contains_key("sso_start_url")
+        || config_section.contains_key("role_arn")
+    {
+        return Err(format!(
+            "AWS profile {name} uses SSO or assume-role; add `credential_process = aws configure export-credentials --profile {name} --format process`"
+        ));
+    }
+    if config.is_empty() && credentials.is_empty() {
+        return Err(
+            "AWS auth missing. Set AWS_ACCESS_KEY_ID/AWS_SECRET_ACCESS_KEY or run `aws configure`."
+                .to_string(),
+        );
+    }
+    Err(format!("AWS profile {name} has no credentials"))
+}
+
+fn non_empty_env(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|v| !v.trim().is_empty())
+}
+
+fn env_credentials() -> Option<Credentials> {
+    Some(Credentials {
+        access_key_id: non_empty_env("AWS_ACCESS_KEY_ID")?,
+        secret_access_key: non_empty_env("AWS_SECRET_ACCESS_KEY")?,
+        session_token: non_empty_env("AWS_SESSION_TOKEN"),
+    })
+}
+
+fn static_credentials(section: &Section) -> Option<Credentials> {
+    Some(Credentials {
+        access_key_id: section.get("aws_access_key_id")?.clone(),
+        secret_access_key: section.get("aws_secret_access_key")?.clone(),
+        session_token: section.get("aws_session_token").cloned(),
+    })
+}
+
+async fn run_credential_process(command: &str) -> Result<Credentials, String> {
+    if let Some((creds, valid_until)) = process_cache()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(command)
+        && *valid_until > Utc::now()
+    {
+        return Ok(creds.clone());
+    }
+
+    let raw = run_cmd("sh", vec!["-c".to_string(), command.to_string()])
+        .await
+        .map_err(|e| format!("credential_process failed: {e}"))?;
+    let out: ProcessOutput = serde_json::from_str(&raw)
+        .map_err(|e| format!("credential_process returned invalid JSON: {e}"))?;
+    let creds = Credentials {
+        access_key_id: out.access_key_id,
+        secret_access_key: out.secret_access_key,
+        session_token: out.session_token,
+    };
+    let valid_until = out
+        .expiration
+        .map_or(Utc::now() + Duration::minutes(15), |exp| {
+            exp - Duration::minutes(1)
+        });
+    process_cache()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(command.to_string(), (creds.clone(), valid_until));
+    Ok(creds)
+}
+
+fn config_path() -> Option<PathBuf> {
+    non_empty_env("AWS_CONFIG_FILE")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".aws/config")))
+}
+
+fn credentials_path() -> Option<PathBuf> {
+    non_empty_env("AWS_SHARED_CREDENTIALS_FILE")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".aws/credentials")))
+}
+
+/// Minimal INI reader for the AWS shared files; indented sub-settings are skipped
+fn read_ini(path: Option<PathBuf>) -> HashMap<String, Section> {
+    let mut sections: HashMap<String, Section> = HashMap::new();
+    let Some(text) = path.and_then(|p| std::fs::read_to_string(p).ok()) else {
+        return sections;
+    };
+
+    let mut current: Option<String> = None;
+    for line in text.lines() {
+        if line.starts_with([' ', '\t']) {
+            continue;
+        }
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(['#', ';']) {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+            sections.entry(name.clone()).or_default();
+            current = Some(name);
+        } else if let (Some(section), Some((key, value))) = (&current, line.split_once('=')) {
+            sections
+                .entry(section.clone())
+                .or_default()
+                .insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    sections
+}
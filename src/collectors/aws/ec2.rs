@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use roxmltree::{Document, Node};
+
+use super::{AwsClient, child_text};
+use crate::collectors::types::AwsInstance;
+use crate::config::AwsConfig;
+
+const EC2_API_VERSION: &str = "2016-11-15";
+
+/// Every instance matching `[aws] tags` / `states`, following `NextToken` across pages
+pub async fn describe_instances(
+    client: &AwsClient,
+    cfg: &AwsConfig,
+) -> Result<Vec<AwsInstance>, String> {
+    let mut params = vec![
+        ("Action".to_string(), "DescribeInstances".to_string()),
+        ("Version".to_string(), EC2_API_VERSION.to_string()),
+        ("MaxResults".to_string(), "1000".to_string()),
+    ];
+    let mut filters = cfg
+        .tags
+        .iter()
+        .map(|(key, value)| (format!("tag:{key}"), vec![value.clone()]))
+        .collect::<Vec<_>>();
+    if !cfg.states.is_empty() {
+        filters.push(("instance-state-name".to_string(), cfg.states.clone()));
+    }
+    for (i, (name, values)) in filters.iter().enumerate() {
+        params.push((format!("Filter.{}.Name", i + 1), name.clone()));
+        for (j, value) in values.iter().enumerate() {
+            params.push((format!("Filter.{}.Value.{}", i + 1, j + 1), value.clone()));
+        }
+    }
+
+    let pages = client
+        .query_pages("ec2", &params, "NextToken", "nextToken")
+        .await?;
+    let mut instances = Vec::new();
+    for body in pages {
+        let doc = Document::parse(&body).map_err(|e| format!("AWS parse error: {e}"))?;
+        instances.extend(
+            doc.descendants()
+                .filter(|n| n.has_tag_name("instancesSet"))
+                .flat_map(|set| set.children().filter(|n| n.has_tag_name("item")))
+                .map(instance),
+        );
+    }
+    Ok(instances)
+}
+
+fn instance(node: Node<'_, '_>) -> AwsInstance {
+    let text = |name: &str, fallback: &str| child_text(node, name).unwrap_or(fallback).to_string();
+    let nested = |parent: &str, name: &str| {
+        node.children()
+            .find(|n| n.has_tag_name(parent))
+            .and_then(|p| child_text(p, name))
+            .map(str::to_string)
+    };
+    // Direct children only: network interfaces carry their own `groupSet`
+    let items = |set: &str| {
+        node.children()
+            .find(|n| n.has_tag_name(set))
+            .into_iter()
+            .flat_map(|set| set.children().filter(|n| n.has_tag_name("item")))
+    };
+    let tags = items("tagSet")
+        .filter_map(|tag| {
+            Some((
+                child_text(tag, "key")?.to_string(),
+                child_text(tag, "value").unwrap_or("").to_string(),
+            ))
+        })
+        .collect::<BTreeMap<_, _>>();
+    let security_groups = items("groupSet")
+        .filter_map(|group| {
+            let id = child_text(group, "groupId")?;
+            Some(match child_text(group, "groupName") {
+                Some(name) => format!("{id} ({name})"),
+                None => id.to_string(),
+            })
+        })
+        .collect();
+    let launch_time = child_text(node, "launchTime")
+        .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| t.with_timezone(&Utc));
+
+    AwsInstance {
+        id: text("instanceId", "unknown"),
+        state: nested("instanceState", "name").unwrap_or_else(|| "?".to_string()),
+        name: tags
+            .get("Name")
+            .cloned()
+            .unwrap_or_else(|| "unnamed".to_string()),
+        instance_type: text("instanceType", "unknown"),
+        az: nested("placement", "availabilityZone").unwrap_or_else(|| "unknown".to_string()),
+        public_ip: text("ipAddress", "none"),
+        private_ip: text("privateIpAddress", "none"),
+        tags,
+        security_groups,
+        vpc_id: child_text(node, "vpcId").map(str::to_string),
+        subnet_id: child_text(node, "subnetId").map(str::to_string),
+        image_id: text("imageId", "unknown"),
+        launch_time,
+        key_name: child_text(node, "keyName").map(str::to_string),
+        iam_profile: nested("iamInstanceProfile", "arn")
+            .map(|arn| arn.rsplit('/').next().unwrap_or(&arn).to_string()),
+        platform: text("platformDetails", "Linux/UNIX"),
+        ..AwsInstance::default()
+    }
+}
+
+/// `start` / `stop` / `reboot` / `terminate` one instance; returns the state EC2 reports next
+pub async fn change_state(client: &AwsClient, id: &str, verb: &str) -> Result<String, String> {
+    let action = match verb {
+        "start" => "StartInstances",
+        "stop" => "StopInstances",
+        "reboot" => "RebootInstances",
+        "terminate" => "TerminateInstances",
+        other => return Err(format!("unsupported EC2 action '{other}'")),
+    };
+    let body = client
+        .query(
+            "ec2",
+            &[
+                ("Action", action),
+                ("Version", EC2_API_VERSION),
+                ("InstanceId.1", id),
+            ],
+        )
+        .await?;
+    let doc = Document::parse(&body).map_err(|e| format!("AWS parse error: {e}"))?;
+
+    // Reboot only answers `<return>true</return>`; the others echo the new state
+    Ok(doc
+        .descendants()
+        .find(|n| n.has_tag_name("currentState"))
+        .and_then(|n| child_text(n, "name"))
+        .unwrap_or("rebooting")
+        .to_string())
+}
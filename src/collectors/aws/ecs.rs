@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use super::AwsClient;
+use crate::collectors::types::EcsService;
+
+const ECS_TARGET: &str = "AmazonEC2ContainerServiceV20141113";
+
+/// A `ListClusters` or `ListServices` page; only one of the ARN lists is filled
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct ArnPage {
+    cluster_arns: Vec<String>,
+    service_arns: Vec<String>,
+    next_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct DescribeServices {
+    #[serde(default)]
+    services: Vec<ApiService>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct ApiService {
+    service_name: String,
+    status: String,
+    desired_count: u64,
+    running_count: u64,
+    pending_count: u64,
+    launch_type: Option<String>,
+}
+
+/// Every service of every cluster with its desired/running task counts
+pub async fn list_services(client: &AwsClient) -> Result<Vec<EcsService>, String> {
+    let clusters = list_arns(client, "ListClusters", None).await?;
+    let mut services = Vec::new();
+    for cluster in clusters {
+        let cluster_name = cluster.rsplit('/').next().unwrap_or(&cluster).to_string();
+        let arns = list_arns(client, "ListServices", Some(&cluster)).await?;
+        // DescribeServices takes at most 10 services per call
+        for chunk in arns.chunks(10) {
+            let page: DescribeServices = client
+                .json(
+                    "ecs",
+                    &format!("{ECS_TARGET}.DescribeServices"),
+                    &json!({ "cluster": cluster, "services": chunk }),
+                )
+                .await?;
+            services.extend(page.services.into_iter().map(|s| EcsService {
+                cluster: cluster_name.clone(),
+                name: s.service_name,
+                status: s.status,
+                desired: s.desired_count,
+                running: s.running_count,
+                pending: s.pending_count,
+                launch_type: s.launch_type.unwrap_or_else(|| "n/a".to_string()),
+                ..EcsService::default()
+            }));
+        }
+    }
+    Ok(services)
+}
+
+/// Follow `nextToken` through `ListClusters` or `ListServices`
+async fn list_arns(
+    client: &AwsClient,
+    operation: &str,
+    cluster: Option<&str>,
+) -> Result<Vec<String>, String> {
+    let mut arns = Vec::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        let mut body = json!({ "maxResults": 100 });
+        if let Some(cluster) = cluster {
+            body["cluster"] = json!(cluster);
+        }
+        if let Some(token) = &next_token {
+            body["nextToken"] = json!(token);
+        }
+        let page: ArnPage = client
+            .json("ecs", &format!("{ECS_TARGET}.{operation}"), &body)
+            .await?;
+        arns.extend(page.cluster_arns);
+        arns.extend(page.service_arns);
+        match page.next_token.filter(|t| !t.is_empty()) {
+            Some(token) if Some(&token) != next_token.as_ref() => next_token = Some(token),
+            _ => return Ok(arns),
+        }
+    }
+}
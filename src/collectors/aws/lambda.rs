@@ -0,0 +1,48 @@
+use serde::Deserialize;
+
+use super::{AwsClient, sigv4::uri_encode};
+use crate::collectors::types::LambdaFunction;
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+struct FunctionPage {
+    #[serde(default)]
+    functions: Vec<ApiFunction>,
+    next_marker: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct ApiFunction {
+    function_name: String,
+    /// Absent for container image functions
+    runtime: Option<String>,
+    last_modified: String,
+    memory_size: u64,
+    timeout: u64,
+}
+
+/// Every function in the region, following `NextMarker`
+pub async fn list_functions(client: &AwsClient) -> Result<Vec<LambdaFunction>, String> {
+    let mut functions = Vec::new();
+    let mut marker: Option<String> = None;
+    loop {
+        let mut path = "/2015-03-31/functions/?MaxItems=50".to_string();
+        if let Some(marker) = &marker {
+            path.push_str(&format!("&Marker={}", uri_encode(marker)));
+        }
+        let page: FunctionPage = client.get_json("lambda", &path).await?;
+        functions.extend(page.functions.into_iter().map(|f| LambdaFunction {
+            name: f.function_name,
+            runtime: f.runtime.unwrap_or_else(|| "image".to_string()),
+            last_modified: f.last_modified,
+            memory_mb: f.memory_size,
+            timeout_secs: f.timeout,
+            ..LambdaFunction::default()
+        }));
+        match page.next_marker.filter(|m| !m.is_empty()) {
+            Some(next) if Some(&next) != marker.as_ref() => marker = Some(next),
+            _ => return Ok(functions),
+        }
+    }
+}
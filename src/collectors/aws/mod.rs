@@ -0,0 +1,482 @@
+mod cloudwatch;
+mod credentials;
+mod ec2;
+mod ecs;
+mod lambda;
+mod rds;
+mod sigv4;
+mod sts;
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::Utc;
+use futures_util::future::join_all;
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+
+use crate::config::AwsConfig;
+
+use super::cache::aws_key;
+use super::types::{AwsResource, AwsStatus, AwsTarget};
+use credentials::{Credentials, ResolvedProfile, resolve};
+
+/// Signs and sends requests to AWS service endpoints for one region
+pub struct AwsClient {
+    http: reqwest::Client,
+    credentials: Credentials,
+    pub region: String,
+    endpoint_url: Option<String>,
+}
+
+impl AwsClient {
+    /// `[aws] endpoint_url` / `AWS_ENDPOINT_URL` (LocalStack, a stub) or the public endpoint
+    pub fn endpoint(&self, service: &str) -> String {
+        match &self.endpoint_url {
+            Some(url) => url.trim_end_matches('/').to_string(),
+            None => format!("https://{service}.{}.amazonaws.com", self.region),
+        }
+    }
+
+    /// POST an AWS Query protocol call (`Action=...&Version=...`) and return the XML body
+    pub async fn query(&self, service: &str, params: &[(&str, &str)]) -> Result<String, String> {
+        let body = params
+            .iter()
+            .map(|(k, v)| format!("{}={}", sigv4::uri_encode(k), sigv4::uri_encode(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+        let url = format!("{}/", self.endpoint(service));
+        self.send(
+            service,
+            "POST",
+            &url,
+            vec![(
+                "content-type".to_string(),
+                "application/x-www-form-urlencoded; charset=utf-8".to_string(),
+            )],
+            body.into_bytes(),
+        )
+        .await
+    }
+
+    /// Run a paginated Query call, sending `token_param` back with each reply's `token_tag` text
+    /// until it runs out; returns every page's XML body
+    pub async fn query_pages(
+        &self,
+        service: &str,
+        params: &[(String, String)],
+        token_param: &str,
+        token_tag: &str,
+    ) -> Result<Vec<String>, String> {
+        let mut pages = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let mut page = params
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect::<Vec<_>>();
+            if let Some(token) = &next_token {
+                page.push((token_param, token.as_str()));
+            }
+            let body = self.query(service, &page).await?;
+            let token = roxmltree::Document::parse(&body)
+                .map_err(|e| format!("AWS parse error: {e}"))?
+                .descendants()
+                .find(|n| n.has_tag_name(token_tag))
+                .and_then(|n| n.text())
+                .filter(|t| !t.is_empty())
+                .map(str::to_string);
+            pages.push(body);
+            if token.is_none() || token == next_token {
+                return Ok(pages);
+            }
+            next_token = token;
+        }
+    }
+
+    /// POST an AWS JSON 1.1 call (`X-Amz-Target: <target>`) and decode the reply
+    pub async fn json<T: DeserializeOwned>(
+        &self,
+        service: &str,
+        target: &str,
+        body: &serde_json::Value,
+    ) -> Result<T, String> {
+        let url = format!("{}/", self.endpoint(service));
+        let text = self
+            .send(
+                service,
+                "POST",
+                &url,
+                vec![
+                    (
+                        "content-type".to_string(),
+                        "application/x-amz-json-1.1".to_string(),
+                    ),
+                    ("x-amz-target".to_string(), target.to_string()),
+                ],
+                body.to_string().into_bytes(),
+            )
+            .await?;
+        serde_json::from_str(&text).map_err(|e| format!("AWS parse error: {e}"))
+    }
+
+    /// GET a REST-JSON path (query string included) and decode the reply
+    pub async fn get_json<T: DeserializeOwned>(
+        &self,
+        service: &str,
+        path: &str,
+    ) -> Result<T, String> {
+        let url = format!("{}{path}", self.endpoint(service));
+        let text = self
+            .send(service, "GET", &url, Vec::new(), Vec::new())
+            .await?;
+        serde_json::from_str(&text).map_err(|e| format!("AWS parse error: {e}"))
+    }
+
+    /// Sign and send a request, mapping transport failures and non-2xx replies to messages
+    pub async fn send(
+        &self,
+        service: &str,
+        method: &str,
+        url: &str,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+    ) -> Result<String, String> {
+        let parsed = Url::parse(url).map_err(|e| format!("bad AWS endpoint '{url}': {e}"))?;
+        let signature = sigv4::sign(
+            Utc::now(),
+            method,
+            &parsed,
+            &headers,
+            &body,
+            service,
+            &self.region,
+            &self.credentials,
+        );
+
+        let method = reqwest::Method::from_bytes(method.as_bytes()).map_err(|e| e.to_string())?;
+        let mut request = self.http.request(method, parsed);
+        for (key, value) in headers.iter().chain(signature.iter()) {
+            request = request.header(key, value);
+        }
+        let response = request.body(body).send().await.map_err(|e| {
+            if e.is_connect() || e.is_timeout() {
+                format!("AWS network/API unreachable ({})", self.endpoint(service))
+            } else {
+                e.to_string()
+            }
+        })?;
+
+        let status = response.status();
+        // REST-JSON services (Lambda) name the error in a header rather than the body
+        let error_type = response
+            .headers()
+            .get("x-amzn-errortype")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(':').next().unwrap_or(v).to_string());
+        let text = response.text().await.map_err(|e| e.to_string())?;
+        if status.is_success() {
+            Ok(text)
+        } else {
+            Err(api_error(status.as_u16(), &text, error_type))
+        }
+    }
+}
+
+/// The region to list when `[aws] regions` is empty
+fn default_region(cfg: &AwsConfig, profile: &ResolvedProfile) -> Option<String> {
+    cfg.region
+        .clone()
+        .filter(|r| !r.trim().is_empty())
+        .or_else(|| std::env::var("AWS_REGION").ok())
+        .or_else(|| std::env::var("AWS_DEFAULT_REGION").ok())
+        .or_else(|| profile.region.clone())
+}
+
+fn configured_profiles(cfg: &AwsConfig) -> Vec<Option<&str>> {
+    if cfg.profiles.is_empty() {
+        vec![cfg.profile.as_deref()]
+    } else {
+        cfg.profiles.iter().map(|p| Some(p.as_str())).collect()
+    }
+}
+
+fn endpoint_url(cfg: &AwsConfig) -> Option<String> {
+    cfg.endpoint_url
+        .clone()
+        .or_else(|| std::env::var("AWS_ENDPOINT_URL").ok())
+        .filter(|u| !u.trim().is_empty())
+}
+
+fn http_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// List every configured profile/region; kinds keyed in `reuse` are left to the cache
+pub async fn collect_aws(cfg: &AwsConfig, reuse: &HashSet<String>) -> AwsStatus {
+    let endpoint_url = endpoint_url(cfg);
+    let http = match http_client() {
+        Ok(http) => http,
+        Err(e) => {
+            return AwsStatus {
+                source: "aws-api".to_string(),
+                error: Some(e),
+                ..AwsStatus::default()
+            };
+        }
+    };
+
+    let profiles = configured_profiles(cfg);
+    let resolved = join_all(profiles.iter().map(|p| resolve(*p))).await;
+
+    // Clients to query, or the target a profile failed on, in config order
+    let mut plans: Vec<Result<(AwsClient, String), AwsTarget>> = Vec::new();
+    for (name, profile) in profiles.iter().zip(resolved) {
+        let failed = |region: &str, error: String| AwsTarget {
+            profile: name.map_or("default credentials".to_string(), |p| {
+                format!("profile {p}")
+            }),
+            account: None,
+            region: region.to_string(),
+            errors: AwsResource::ALL
+                .iter()
+                .map(|kind| (*kind, error.clone()))
+                .collect(),
+        };
+        let profile = match profile {
+            Ok(profile) => profile,
+            Err(e) => {
+                let region = match cfg.regions.as_slice() {
+                    [] => cfg.region.clone().unwrap_or_else(|| "?".to_string()),
+                    regions => regions.join(","),
+                };
+                plans.push(Err(failed(&region, e)));
+                continue;
+            }
+        };
+        let regions = if cfg.regions.is_empty() {
+            match default_region(cfg, &profile) {
+                Some(region) => vec![region],
+                None => {
+                    plans.push(Err(failed(
+                        "?",
+                        "AWS region missing. Set [aws].region in devdash.toml or AWS_REGION."
+                            .to_string(),
+                    )));
+                    continue;
+                }
+            }
+        } else {
+            cfg.regions.clone()
+        };
+        for region in regions {
+            plans.push(Ok((
+                AwsClient {
+                    http: http.clone(),
+                    credentials: profile.credentials.clone(),
+                    region,
+                    endpoint_url: endpoint_url.clone(),
+                },
+                profile.source.clone(),
+            )));
+        }
+    }
+
+    let listed = join_all(plans.into_iter().map(|plan| async move {
+        match plan {
+            Ok((client, profile)) => list_target(client, profile, cfg, reuse).await,
+            Err(target) => (target, AwsStatus::default()),
+        }
+    }))
+    .await;
+
+    let mut source = match listed.as_slice() {
+        [(target, _)] => format!("aws-api ({}, region={}", target.profile, target.region),
+        _ => format!("aws-api ({} targets", listed.len()),
+    };
+    if let Some(url) = &endpoint_url {
+        source.push_str(&format!(", endpoint={url}"));
+    }
+    source.push(')');
+
+    let failed = listed
+        .iter()
+        .filter(|(t, _)| t.error(AwsResource::Ec2).is_some())
+        .count();
+    let error = match listed.as_slice() {
+        [(target, _)] => target.error(AwsResource::Ec2).map(str::to_string),
+        _ if failed == listed.len() => Some(format!("all {failed} AWS targets failed")),
+        _ => None,
+    };
+    let (mut targets, found): (Vec<_>, Vec<_>) = listed.into_iter().unzip();
+    // STS may fail in the same region EC2 does; the profile's other regions know the account
+    for i in 0..targets.len() {
+        if targets[i].account.is_none() {
+            targets[i].account = targets
+                .iter()
+                .find(|t| t.profile == targets[i].profile && t.account.is_some())
+                .and_then(|t| t.account.clone());
+        }
+    }
+
+    let mut status = AwsStatus {
+        targets,
+        source,
+        error,
+        ..AwsStatus::default()
+    };
+    for part in found {
+        status.extend(part);
+    }
+    status
+}
+
+/// Start, stop, reboot or terminate an instance listed under `profile` (an `AwsTarget` label)
+/// in `region`; returns the instance's next state
+pub async fn instance_action(
+    cfg: &AwsConfig,
+    profile: &str,
+    region: &str,
+    id: &str,
+    verb: &str,
+) -> Result<String, String> {
+    let mut resolved = None;
+    for name in configured_profiles(cfg) {
+        if let Ok(candidate) = resolve(name).await
+            && candidate.source == profile
+        {
+            resolved = Some(candidate);
+            break;
+        }
+    }
+    let resolved = resolved.ok_or(format!("AWS credentials for {profile} are gone"))?;
+
+    let client = AwsClient {
+        http: http_client()?,
+        credentials: resolved.credentials,
+        region: region.to_string(),
+        endpoint_url: endpoint_url(cfg),
+    };
+    ec2::change_state(&client, id, verb).await
+}
+
+/// Everything listed for one profile/region pair, each item tagged with where it came from
+async fn list_target(
+    client: AwsClient,
+    profile: String,
+    cfg: &AwsConfig,
+    reuse: &HashSet<String>,
+) -> (AwsTarget, AwsStatus) {
+    let skip = |kind| reuse.contains(&aws_key(&profile, &client.region, kind));
+    let (account, instances, ecs, lambdas, databases, alarms) = tokio::join!(
+        sts::account_id(&client),
+        ec2::describe_instances(&client, cfg),
+        unless(skip(AwsResource::Ecs), ecs::list_services(&client)),
+        unless(skip(AwsResource::Lambda), lambda::list_functions(&client)),
+        unless(skip(AwsResource::Rds), rds::describe_db_instances(&client)),
+        unless(
+            skip(AwsResource::Alarms),
+            cloudwatch::alarms_firing(&client)
+        )
+    );
+
+    let mut found = AwsStatus::default();
+    let mut errors = Vec::new();
+    match ecs {
+        Some(Ok(items)) => found.ecs = items,
+        Some(Err(e)) => errors.push((AwsResource::Ecs, e)),
+        None => {}
+    }
+    match lambdas {
+        Some(Ok(items)) => found.lambdas = items,
+        Some(Err(e)) => errors.push((AwsResource::Lambda, e)),
+        None => {}
+    }
+    match databases {
+        Some(Ok(items)) => found.databases = items,
+        Some(Err(e)) => errors.push((AwsResource::Rds, e)),
+        None => {}
+    }
+    match alarms {
+        Some(Ok(items)) => found.alarms = items,
+        Some(Err(e)) => errors.push((AwsResource::Alarms, e)),
+        None => {}
+    }
+
+    match instances {
+        Ok(instances) => found.items = instances,
+        Err(e) => errors.insert(0, (AwsResource::Ec2, e)),
+    }
+    found.set_scope(&profile, &client.region);
+
+    let target = AwsTarget {
+        profile,
+        account: account.ok(),
+        region: client.region.clone(),
+        errors,
+    };
+    (target, found)
+}
+
+/// Run `listing` unless the cache already holds a recent enough copy
+async fn unless<T>(
+    skip: bool,
+    listing: impl Future<Output = Result<T, String>>,
+) -> Option<Result<T, String>> {
+    if skip { None } else { Some(listing.await) }
+}
+
+/// Query APIs answer `<Errors><Error><Code>..</Code><Message>..</Message>`, JSON APIs
+/// `{"__type": "..", "message": ".."}`
+fn api_error(status: u16, body: &str, error_type: Option<String>) -> String {
+    let (code, message) = if let Ok(doc) = roxmltree::Document::parse(body) {
+        let find = |name: &str| {
+            doc.descendants()
+                .find(|n| n.has_tag_name(name))
+                .and_then(|n| n.text())
+                .map(str::to_string)
+        };
+        (find("Code"), find("Message"))
+    } else if let Ok(json) = serde_json::from_str::<serde_json::Value>(body) {
+        let field = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|n| json.get(*n).and_then(|v| v.as_str()))
+                .map(|s| s.rsplit('#').next().unwrap_or(s).to_string())
+        };
+        (field(&["__type", "code"]), field(&["message", "Message"]))
+    } else {
+        (None, None)
+    };
+
+    match (code.or(error_type), message) {
+        (Some(code), Some(message)) if is_auth_error(&code) => {
+            format!("AWS auth rejected ({code}): {message}")
+        }
+        (Some(code), Some(message)) => format!("AWS {code}: {message}"),
+        (Some(code), None) => format!("AWS {code} (HTTP {status})"),
+        _ => format!("AWS API HTTP {status}"),
+    }
+}
+
+fn is_auth_error(code: &str) -> bool {
+    matches!(
+        code,
+        "AuthFailure"
+            | "UnrecognizedClientException"
+            | "InvalidClientTokenId"
+            | "SignatureDoesNotMatch"
+            | "ExpiredToken"
+            | "RequestExpired"
+    )
+}
+
+/// Text of the first direct child element called `name`
+fn child_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|n| n.has_tag_name(name))
+        .and_then(|n| n.text())
+}
@@ -0,0 +1,47 @@
+use roxmltree::Document;
+
+use super::{AwsClient, child_text};
+use crate::collectors::types::RdsInstance;
+
+const RDS_API_VERSION: &str = "2014-10-31";
+
+/// Every DB instance in the region, following `Marker`
+pub async fn describe_db_instances(client: &AwsClient) -> Result<Vec<RdsInstance>, String> {
+    let params = [
+        ("Action", "DescribeDBInstances"),
+        ("Version", RDS_API_VERSION),
+        ("MaxRecords", "100"),
+    ]
+    .map(|(k, v)| (k.to_string(), v.to_string()));
+    let pages = client
+        .query_pages("rds", &params, "Marker", "Marker")
+        .await?;
+
+    let mut databases = Vec::new();
+    for body in pages {
+        let doc = Document::parse(&body).map_err(|e| format!("AWS parse error: {e}"))?;
+        databases.extend(
+            doc.descendants()
+                .filter(|n| n.has_tag_name("DBInstance"))
+                .map(|node| {
+                    let text = |name: &str| child_text(node, name).unwrap_or("").to_string();
+                    RdsInstance {
+                        id: text("DBInstanceIdentifier"),
+                        status: text("DBInstanceStatus"),
+                        engine: text("Engine"),
+                        engine_version: text("EngineVersion"),
+                        class: text("DBInstanceClass"),
+                        endpoint: node
+                            .children()
+                            .find(|n| n.has_tag_name("Endpoint"))
+                            .and_then(|e| child_text(e, "Address"))
+                            .unwrap_or("none")
+                            .to_string(),
+                        multi_az: child_text(node, "MultiAZ") == Some("true"),
+                        ..RdsInstance::default()
+                    }
+                }),
+        );
+    }
+    Ok(databases)
+}
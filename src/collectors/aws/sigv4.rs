@@ -0,0 +1,280 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+
+use super::credentials::Credentials;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Headers (`x-amz-date`, optional `x-amz-security-token`, `authorization`) that sign a request
+/// with AWS Signature Version 4 at time `now`. `headers` are the other headers sent, all of which
+/// get signed.
+#[allow(clippy::too_many_arguments)]
+pub fn sign(
+    now: DateTime<Utc>,
+    method: &str,
+    url: &Url,
+    headers: &[(String, String)],
+    body: &[u8],
+    service: &str,
+    region: &str,
+    credentials: &Credentials,
+) -> Vec<(String, String)> {
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+
+    let mut extra = vec![("x-amz-date".to_string(), amz_date.clone())];
+    if let Some(token) = &credentials.session_token {
+        extra.push(("x-amz-security-token".to_string(), token.clone()));
+    }
+
+    let host = match url.port() {
+        Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+        None => url.host_str().unwrap_or_default().to_string(),
+    };
+    let mut signed: Vec<(String, String)> = headers
+        .iter()
+        .chain(extra.iter())
+        .map(|(k, v)| (k.to_ascii_lowercase(), v.trim().to_string()))
+        .chain(std::iter::once(("host".to_string(), host)))
+        .collect();
+    signed.sort();
+
+    let canonical_headers: String = signed.iter().map(|(k, v)| format!("{k}:{v}\n")).collect();
+    let signed_headers = signed
+        .iter()
+        .map(|(k, _)| k.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let canonical_request = format!(
+        "{method}\n{}\n{}\n{canonical_headers}\n{signed_headers}\n{}",
+        canonical_path(url),
+        canonical_query(url),
+        hex::encode(Sha256::digest(body))
+    );
+
+    let scope = format!("{date}/{region}/{service}/aws4_request");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let key = [date.as_str(), region, service, "aws4_request"]
+        .iter()
+        .fold(
+            format!("AWS4{}", credentials.secret_access_key).into_bytes(),
+            |key, part| hmac(&key, part.as_bytes()),
+        );
+    let signature = hex::encode(hmac(&key, string_to_sign.as_bytes()));
+
+    extra.push((
+        "authorization".to_string(),
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            credentials.access_key_id
+        ),
+    ));
+    extra
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Path segments are encoded once more on top of the URL's own encoding, as non-S3 services expect
+fn canonical_path(url: &Url) -> String {
+    let path = url.path();
+    if path.is_empty() {
+        return "/".to_string();
+    }
+    path.split('/')
+        .map(uri_encode)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn canonical_query(url: &Url) -> String {
+    let mut pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(k, v)| (uri_encode(&k), uri_encode(&v)))
+        .collect();
+    pairs.sort();
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// RFC 3986 encoding of everything but unreserved characters
+pub fn uri_encode(raw: &str) -> String {
+    raw.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_credentials(session_token: Option<&str>) -> Credentials {
+        Credentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: session_token.map(str::to_string),
+        }
+    }
+
+    fn header<'a>(headers: &'a [(String, String)], name: &str) -> &'a str {
+        headers
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// A request from AWS's SigV4 test suite, signed at its fixed 2015-08-30T12:36:00Z
+    fn sign_suite_request(
+        method: &str,
+        url: &str,
+        headers: &[(String, String)],
+        body: &[u8],
+    ) -> Vec<(String, String)> {
+        let now = "2015-08-30T12:36:00Z".parse().unwrap();
+        let url = Url::parse(url).unwrap();
+        sign(
+            now,
+            method,
+            &url,
+            headers,
+            body,
+            "service",
+            "us-east-1",
+            &example_credentials(None),
+        )
+    }
+
+    #[test]
+    fn signs_get_vanilla() {
+        let signed = sign_suite_request("GET", "https://example.amazonaws.com/", &[], b"");
+        assert_eq!(header(&signed, "x-amz-date"), "20150830T123600Z");
+        assert_eq!(
+            header(&signed, "authorization"),
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+
+    #[test]
+    fn signs_get_vanilla_query_order_key_case() {
+        let signed = sign_suite_request(
+            "GET",
+            "https://example.amazonaws.com/?Param2=value2&Param1=value1",
+            &[],
+            b"",
+        );
+        assert!(header(&signed, "authorization").ends_with(
+            "Signature=b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500"
+        ));
+    }
+
+    #[test]
+    fn signs_post_x_www_form_urlencoded() {
+        let headers = [(
+            "Content-Type".to_string(),
+            "application/x-www-form-urlencoded".to_string(),
+        )];
+        let signed = sign_suite_request(
+            "POST",
+            "https://example.amazonaws.com/",
+            &headers,
+            b"Param1=value1",
+        );
+        assert!(header(&signed, "authorization").ends_with(
+            "SignedHeaders=content-type;host;x-amz-date, \
+             Signature=ff11897932ad3f4e8b18135d722051e5ac45fc38421b1da7b9d196a0fe09473a"
+        ));
+    }
+
+    #[test]
+    fn signs_paginated_lambda_call_with_session_token() {
+        let marker = "eyJNYXJrZXIiOiBudWxsfQ==:abc/def+1";
+        let url = Url::parse(&format!(
+            "https://lambda.us-east-1.amazonaws.com/2015-03-31/functions/?MaxItems=50&Marker={}",
+            uri_encode(marker)
+        ))
+        .unwrap();
+        let signed = sign(
+            "2026-10-18T09:30:00Z".parse().unwrap(),
+            "GET",
+            &url,
+            &[],
+            b"",
+            "lambda",
+            "us-east-1",
+            &example_credentials(Some("session-token-example")),
+        );
+        assert_eq!(
+            header(&signed, "x-amz-security-token"),
+            "session-token-example"
+        );
+        assert_eq!(
+            header(&signed, "authorization"),
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20261018/us-east-1/lambda/aws4_request, \
+             SignedHeaders=host;x-amz-date;x-amz-security-token, \
+             Signature=3f2dabd2be442ba4caf07bfde632e03c8dc888da8f8baefde3826b721d43054c"
+        );
+    }
+
+    #[test]
+    fn encodes_reserved_characters() {
+        assert_eq!(uri_encode("AZaz09-_.~"), "AZaz09-_.~");
+        assert_eq!(
+            uri_encode("a b+c/d=e&f:g*h"),
+            "a%20b%2Bc%2Fd%3De%26f%3Ag%2Ah"
+        );
+        assert_eq!(uri_encode("é"), "%C3%A9");
+    }
+
+    #[test]
+    fn canonicalises_paths() {
+        let path = |url: &str| canonical_path(&Url::parse(url).unwrap());
+        assert_eq!(path("https://ec2.us-east-1.amazonaws.com"), "/");
+        assert_eq!(
+            path("https://lambda.us-east-1.amazonaws.com/2015-03-31/functions/"),
+            "/2015-03-31/functions/"
+        );
+        // Already percent-encoded by the URL, encoded once more
+        assert_eq!(
+            path("https://example.amazonaws.com/a b/ሴ"),
+            "/a%2520b/%25E1%2588%25B4"
+        );
+    }
+
+    #[test]
+    fn canonicalises_queries() {
+        let query = |url: &str| canonical_query(&Url::parse(url).unwrap());
+        assert_eq!(query("https://example.amazonaws.com/"), "");
+        assert_eq!(
+            query("https://example.amazonaws.com/?b=2&a=1&a=&c"),
+            "a=&a=1&b=2&c="
+        );
+        let marker = uri_encode("eyJNYXJrZXIiOiBudWxsfQ==:abc/def+1");
+        assert_eq!(
+            query(&format!(
+                "https://lambda.us-east-1.amazonaws.com/2015-03-31/functions/?MaxItems=50&Marker={marker}"
+            )),
+            "Marker=eyJNYXJrZXIiOiBudWxsfQ%3D%3D%3Aabc%2Fdef%2B1&MaxItems=50"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use roxmltree::Document;
+
+use super::AwsClient;
+
+/// Account ids by access key id; a key never moves between accounts
+fn account_cache() -> &'static Mutex<HashMap<String, String>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Account id of the client's credentials via `GetCallerIdentity`, looked up once per key
+pub async fn account_id(client: &AwsClient) -> Result<String, String> {
+    let key = client.credentials.access_key_id.clone();
+    if let Some(account) = account_cache()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&key)
+    {
+        return Ok(account.clone());
+    }
+
+    let body = client
+        .query(
+            "sts",
+            &[("Action", "GetCallerIdentity"), ("Version", "2011-06-15")],
+        )
+        .await?;
+    let doc = Document::parse(&body).map_err(|e| format!("AWS parse error: {e}"))?;
+    let account = doc
+        .descendants()
+        .find(|n| n.has_tag_name("Account"))
+        .and_then(|n| n.text())
+        .ok_or("STS reply has no Account")?
+        .to_string();
+    account_cache()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key, account.clone());
+    Ok(account)
+}
@@ -1,25 +1,51 @@
+use std::collections::{HashMap, HashSet};
+
 use chrono::{DateTime, Utc};
 
-use super::types::{AwsStatus, DashboardData, PrStatus};
+use super::types::{AwsResource, AwsStatus, DashboardData, PrStatus};
+
+/// ECS, Lambda, RDS and alarm listings change slowly; each is fetched at most this often per
+/// profile/region, with the cached listing shown in between
+const AWS_RESOURCE_REFRESH_SECS: i64 = 60;
+
+/// Key of one profile/region/resource kind snapshot
+pub(super) fn aws_key(profile: &str, region: &str, kind: AwsResource) -> String {
+    format!("{profile}/{region}/{}", kind.label())
+}
 
 pub fn apply_cache(data: &mut DashboardData, cache: &mut DataCache, cache_seconds: u64) {
     let now = Utc::now();
 
-    match &data.aws.error {
-        None => {
-            cache.aws = Some((data.aws.clone(), now));
-        }
-        Some(err) => {
-            if let Some((cached, ts)) = &cache.aws
+    // Each profile/region and resource kind falls back on its own, so one failing region or
+    // missing permission keeps the rest live; kinds skipped by `aws_reusable` come back from
+    // the cache with their original fetch time
+    let reused = std::mem::take(&mut cache.aws_reused);
+    let aws = &mut data.aws;
+    let mut targets = std::mem::take(&mut aws.targets);
+    let mut restored = AwsStatus::default();
+    for target in &mut targets {
+        for kind in AwsResource::ALL {
+            let key = aws_key(&target.profile, &target.region, kind);
+            if target.error(kind).is_none() {
+                if !reused.contains(&key) {
+                    cache.aws.insert(key, (aws.subset(target, kind), now));
+                } else if let Some((cached, _)) = cache.aws.get(&key) {
+                    restored.extend(cached.clone());
+                }
+            } else if let Some((cached, ts)) = cache.aws.get(&key)
                 && (now - *ts).num_seconds() <= cache_seconds as i64
             {
-                data.aws.instances = cached.instances.clone();
-                data.aws.items = cached.items.clone();
-                data.aws.source = format!("{} (cached)", cached.source);
-                data.aws.error = Some(format!("{} | showing cached data", err));
+                restored.extend(cached.clone());
+                for (k, err) in &mut target.errors {
+                    if *k == kind {
+                        err.push_str(" | showing cached data");
+                    }
+                }
             }
         }
     }
+    aws.targets = targets;
+    aws.extend(restored);
 
     match &data.prs.error {
         None => {
@@ -29,8 +55,7 @@ pub fn apply_cache(data: &mut DashboardData, cache: &mut DataCache, cache_second
             if let Some((cached, ts)) = &cache.prs
                 && (now - *ts).num_seconds() <= cache_seconds as i64
             {
-                data.prs.open = cached.open.clone();
-                data.prs.items = cached.items.clone();
+                data.prs.views = cached.views.clone();
                 data.prs.source = format!("{} (cached)", cached.source);
                 data.prs.error = Some(format!("{} | showing cached data", err));
             }
@@ -40,6 +65,26 @@ pub fn apply_cache(data: &mut DashboardData, cache: &mut DataCache, cache_second
 
 #[derive(Debug, Default)]
 pub struct DataCache {
-    aws: Option<(AwsStatus, DateTime<Utc>)>,
+    aws: HashMap<String, (AwsStatus, DateTime<Utc>)>,
+    /// Keys handed out by the last `aws_reusable`, restored rather than overwritten
+    aws_reused: HashSet<String>,
     prs: Option<(PrStatus, DateTime<Utc>)>,
 }
+
+impl DataCache {
+    /// Slow-moving AWS kinds fetched recently enough to skip this round; `apply_cache` fills
+    /// them back in from the cache
+    pub fn aws_reusable(&mut self) -> HashSet<String> {
+        let now = Utc::now();
+        let ec2 = format!("/{}", AwsResource::Ec2.label());
+        self.aws_reused = self
+            .aws
+            .iter()
+            .filter(|(key, (_, ts))| {
+                !key.ends_with(&ec2) && (now - *ts).num_seconds() < AWS_RESOURCE_REFRESH_SECS
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        self.aws_reused.clone()
+    }
+}
@@ -0,0 +1,794 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use crate::config::DockerConfig;
+use crate::streams::StreamLine;
+
+use super::engine::{self, Endpoint};
+use super::gather::run_cmd;
+use super::runtime::{Runtime, RuntimeKind};
+use super::storage::collect_storage;
+use super::types::{ContainerStats, DockerContainer, DockerEvent, DockerStatus};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiContainer {
+    id: String,
+    #[serde(default)]
+    names: Vec<String>,
+    #[serde(default)]
+    image: String,
+    #[serde(default)]
+    created: i64,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    ports: Vec<ApiPort>,
+    #[serde(default)]
+    labels: Option<BTreeMap<String, String>>,
+    #[serde(default)]
+    network_settings: Option<ApiNetworkSettings>,
+    #[serde(default)]
+    mounts: Vec<ApiMount>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiPort {
+    #[serde(rename = "IP", default)]
+    ip: Option<String>,
+    private_port: u16,
+    #[serde(default)]
+    public_port: Option<u16>,
+    #[serde(rename = "Type", default)]
+    kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiNetworkSettings {
+    #[serde(default)]
+    networks: BTreeMap<String, ApiNetwork>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiNetwork {
+    #[serde(rename = "IPAddress", default)]
+    ip_address: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiMount {
+    #[serde(rename = "Type", default)]
+    kind: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    source: String,
+    #[serde(default)]
+    destination: String,
+    #[serde(rename = "RW", default)]
+    rw: bool,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ApiStats {
+    #[serde(default)]
+    cpu_stats: ApiCpuStats,
+    #[serde(default)]
+    memory_stats: ApiMemoryStats,
+    #[serde(default)]
+    networks: Option<HashMap<String, ApiNetStats>>,
+    #[serde(default)]
+    blkio_stats: ApiBlkioStats,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ApiCpuStats {
+    #[serde(default)]
+    cpu_usage: ApiCpuUsage,
+    #[serde(default)]
+    system_cpu_usage: Option<u64>,
+    #[serde(default)]
+    online_cpus: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ApiCpuUsage {
+    #[serde(default)]
+    total_usage: u64,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ApiMemoryStats {
+    #[serde(default)]
+    usage: u64,
+    #[serde(default)]
+    limit: u64,
+    #[serde(default)]
+    stats: HashMap<String, u64>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ApiNetStats {
+    #[serde(default)]
+    rx_bytes: u64,
+    #[serde(default)]
+    tx_bytes: u64,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ApiBlkioStats {
+    #[serde(default)]
+    io_service_bytes_recursive: Option<Vec<ApiBlkioEntry>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiBlkioEntry {
+    #[serde(default)]
+    op: String,
+    #[serde(default)]
+    value: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiEvent {
+    #[serde(default)]
+    action: String,
+    #[serde(default)]
+    actor: ApiActor,
+    #[serde(rename = "time", default)]
+    time: i64,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+struct ApiActor {
+    #[serde(rename = "ID", default)]
+    id: String,
+    #[serde(default)]
+    attributes: HashMap<String, String>,
+}
+
+/// Container lifecycle events worth surfacing; exec_* noise from health checks is left out
+const EVENT_ACTIONS: [&str; 10] = [
+    "create", "start", "restart", "stop", "die", "kill", "oom", "pause", "unpause", "destroy",
+];
+
+/// Previous (container cpu, system cpu) totals per container for CPU % deltas
+fn cpu_samples() -> &'static Mutex<HashMap<String, (u64, u64)>> {
+    static SAMPLES: OnceLock<Mutex<HashMap<String, (u64, u64)>>> = OnceLock::new();
+    SAMPLES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub async fn collect_docker(cfg: &DockerConfig) -> DockerStatus {
+    let runtime = match Runtime::detect(cfg) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            return DockerStatus {
+                error: Some(e),
+                ..DockerStatus::default()
+            };
+        }
+    };
+
+    let listed = match &runtime.endpoint {
+        Some(endpoint) => list_containers(endpoint).await,
+        None => list_containers_cli(runtime.kind).await,
+    };
+    match listed {
+        Ok(mut items) => {
+            let storage = match &runtime.endpoint {
+                Some(endpoint) => {
+                    let (_, storage) = tokio::join!(
+                        attach_stats(endpoint, &mut items),
+                        collect_storage(&runtime)
+                    );
+                    storage
+                }
+                None => collect_storage(&runtime).await,
+            };
+            DockerStatus {
+                runtime: runtime.name().to_string(),
+                source: runtime.describe(),
+                items,
+                storage,
+                error: None,
+            }
+        }
+        Err(e) => DockerStatus {
+            runtime: runtime.name().to_string(),
+            source: runtime.describe(),
+            error: Some(format!("{}: {e}", runtime.name())),
+            ..DockerStatus::default()
+        },
+    }
+}
+
+async fn attach_stats(endpoint: &Endpoint, items: &mut [DockerContainer]) {
+    let stats = futures_util::future::join_all(
+        items
+            .iter()
+            .filter(|c| c.state == "running")
+            .map(|c| container_stats(endpoint, c.id.clone())),
+    )
+    .await
+    .into_iter()
+    .flatten()
+    .collect::<HashMap<_, _>>();
+
+    let mut samples = cpu_samples().lock().unwrap_or_else(|e| e.into_inner());
+    samples.retain(|id, _| stats.contains_key(id));
+    for c in items {
+        if let Some((raw, totals)) = stats.get(&c.id) {
+            let mut sample = raw.clone();
+            sample.cpu_pct = cpu_pct(samples.get(&c.id).copied(), *totals);
+            samples.insert(c.id.clone(), (totals.0, totals.1));
+            c.stats = Some(sample);
+        }
+    }
+}
+
+/// Run a lifecycle verb (`start`, `stop`, `restart`, `pause`, `unpause`, `remove`) on a container
+pub async fn container_action(cfg: &DockerConfig, id: &str, verb: &str) -> Result<(), String> {
+    let runtime = Runtime::detect(cfg)?;
+    let result = match &runtime.endpoint {
+        Some(endpoint) => {
+            let (method, path) = match verb {
+                "remove" => ("DELETE", format!("/containers/{id}")),
+                _ => ("POST", format!("/containers/{id}/{verb}")),
+            };
+            engine::request(endpoint, method, &path, None)
+                .await
+                .map(|_| ())
+        }
+        None => {
+            let verb = if verb == "remove" { "rm" } else { verb };
+            run_cmd(runtime.name(), vec![verb.to_string(), id.to_string()])
+                .await
+                .map(|_| ())
+        }
+    };
+    result.map_err(|e| format!("{}: {e}", runtime.name()))
+}
+
+/// Stream `docker logs -f --timestamps` over the API, tagging each line with `tag`
+pub async fn follow_container_logs(
+    endpoint: &Endpoint,
+    id: &str,
+    tail: Option<u32>,
+    since_secs: Option<u64>,
+    tag: &str,
+    tx: &mpsc::Sender<StreamLine>,
+) -> Result<(), String> {
+    let mut path = format!("/containers/{id}/logs?follow=1&stdout=1&stderr=1&timestamps=1&tail=");
+    match tail {
+        Some(n) => path.push_str(&n.to_string()),
+        None => path.push_str("all"),
+    }
+    if let Some(secs) = since_secs {
+        let since = Utc::now().timestamp() - secs as i64;
+        path.push_str(&format!("&since={since}"));
+    }
+
+    let mut response = engine::open(endpoint, "GET", &path, None).await?;
+    if !(200..300).contains(&response.status) {
+        let status = response.status;
+        let body = response.read_all().await.unwrap_or_default();
+        return Err(engine::api_error(status, &body));
+    }
+
+    // Containers without a TTY multiplex stdout/stderr behind 8-byte frame headers
+    let mut multiplexed: Option<bool> = None;
+    let mut raw = Vec::new();
+    let mut text = Vec::new();
+    while let Some(chunk) = response.next_chunk().await? {
+        raw.extend_from_slice(&chunk);
+        if multiplexed.is_none() {
+            if raw.len() < 8 {
+                continue;
+            }
+            multiplexed = Some(matches!(raw[0], 0..=2) && raw[1..4] == [0, 0, 0]);
+        }
+        if multiplexed == Some(true) {
+            while raw.len() >= 8 {
+                let size = u32::from_be_bytes([raw[4], raw[5], raw[6], raw[7]]) as usize;
+                if raw.len() < 8 + size {
+                    break;
+                }
+                text.extend_from_slice(&raw[8..8 + size]);
+                raw.drain(..8 + size);
+            }
+        } else {
+            text.append(&mut raw);
+        }
+
+        while let Some(pos) = text.iter().position(|b| *b == b'\n') {
+            let line = String::from_utf8_lossy(&text[..pos]).trim_end().to_string();
+            text.drain(..=pos);
+            let line = StreamLine {
+                source: tag.to_string(),
+                text: line,
+            };
+            if tx.send(line).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    // Flush an unterminated last line (or a short raw stream never classified)
+    if multiplexed.is_none() {
+        text.append(&mut raw);
+    }
+    let rest = String::from_utf8_lossy(&text).trim_end().to_string();
+    if !rest.is_empty() {
+        let _ = tx
+            .send(StreamLine {
+                source: tag.to_string(),
+                text: rest,
+            })
+            .await;
+    }
+    Ok(())
+}
+
+/// One-shot stats for a container plus its raw (container, system, cpus) CPU counters
+async fn container_stats(
+    endpoint: &Endpoint,
+    id: String,
+) -> Option<(String, (ContainerStats, (u64, u64, u32)))> {
+    let path = format!("/containers/{id}/stats?stream=false&one-shot=true");
+    let body = engine::request(endpoint, "GET", &path, None).await.ok()?;
+    let raw = serde_json::from_slice::<ApiStats>(&body).ok()?;
+
+    // Match `docker stats`: page cache does not count towards usage
+    let cache = raw
+        .memory_stats
+        .stats
+        .get("inactive_file")
+        .or_else(|| raw.memory_stats.stats.get("total_inactive_file"))
+        .copied()
+        .unwrap_or(0);
+    let (net_rx_bytes, net_tx_bytes) = raw
+        .networks
+        .unwrap_or_default()
+        .values()
+        .fold((0, 0), |(rx, tx), n| (rx + n.rx_bytes, tx + n.tx_bytes));
+    let (blk_read_bytes, blk_write_bytes) = raw
+        .blkio_stats
+        .io_service_bytes_recursive
+        .unwrap_or_default()
+        .iter()
+        .fold((0, 0), |(r, w), e| {
+            match e.op.to_ascii_lowercase().as_str() {
+                "read" => (r + e.value, w),
+                "write" => (r, w + e.value),
+                _ => (r, w),
+            }
+        });
+
+    let stats = ContainerStats {
+        cpu_pct: None,
+        mem_used_bytes: raw.memory_stats.usage.saturating_sub(cache),
+        mem_limit_bytes: raw.memory_stats.limit,
+        net_rx_bytes,
+        net_tx_bytes,
+        blk_read_bytes,
+        blk_write_bytes,
+    };
+    let cpu = &raw.cpu_stats;
+    let totals = (
+        cpu.cpu_usage.total_usage,
+        cpu.system_cpu_usage.unwrap_or(0),
+        cpu.online_cpus.unwrap_or(1).max(1),
+    );
+    Some((id, (stats, totals)))
+}
+
+fn cpu_pct(prev: Option<(u64, u64)>, (container, system, cpus): (u64, u64, u32)) -> Option<f64> {
+    let (prev_container, prev_system) = prev?;
+    let container_delta = container.checked_sub(prev_container)? as f64;
+    let system_delta = system.checked_sub(prev_system)? as f64;
+    (system_delta > 0.0).then(|| container_delta / system_delta * cpus as f64 * 100.0)
+}
+
+/// Stream container events from `/events` until the connection drops or the receiver is gone
+pub async fn follow_docker_events(
+    endpoint: &Endpoint,
+    tx: &mpsc::Sender<DockerEvent>,
+) -> Result<(), String> {
+    // {"type":["container"]}
+    let path = "/events?filters=%7B%22type%22%3A%5B%22container%22%5D%7D";
+    let mut response = engine::open(endpoint, "GET", path, None).await?;
+    if !(200..300).contains(&response.status) {
+        let status = response.status;
+        let body = response.read_all().await.unwrap_or_default();
+        return Err(engine::api_error(status, &body));
+    }
+
+    let mut buf = Vec::new();
+    while let Some(chunk) = response.next_chunk().await? {
+        buf.extend_from_slice(&chunk);
+        while let Some(pos) = buf.iter().position(|b| *b == b'\n') {
+            let line = buf.drain(..=pos).collect::<Vec<_>>();
+            let Ok(event) = serde_json::from_slice::<ApiEvent>(&line) else {
+                continue;
+            };
+            let Some(event) = into_event(event) else {
+                continue;
+            };
+            if tx.send(event).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn into_event(e: ApiEvent) -> Option<DockerEvent> {
+    // Health changes arrive as `health_status: unhealthy`
+    let (action, detail) = match e.action.split_once(": ") {
+        Some(("health_status", health)) => ("health_status", Some(health.to_string())),
+        Some(_) => return None,
+        None if EVENT_ACTIONS.contains(&e.action.as_str()) => {
+            let detail = match e.action.as_str() {
+                "die" => e
+                    .actor
+                    .attributes
+                    .get("exitCode")
+                    .map(|c| format!("exit {c}")),
+                "kill" => e
+                    .actor
+                    .attributes
+                    .get("signal")
+                    .map(|s| format!("signal {s}")),
+                _ => None,
+            };
+            (e.action.as_str(), detail)
+        }
+        None => return None,
+    };
+    Some(DockerEvent {
+        time: DateTime::<Utc>::from_timestamp(e.time, 0).unwrap_or_else(Utc::now),
+        id: short_id(&e.actor.id),
+        name: e.actor.attributes.get("name").cloned().unwrap_or_default(),
+        action: action.to_string(),
+        detail,
+    })
+}
+
+async fn list_containers(endpoint: &Endpoint) -> Result<Vec<DockerContainer>, String> {
+    let body = engine::request(endpoint, "GET", "/containers/json?all=1", None).await?;
+    let rows = serde_json::from_slice::<Vec<ApiContainer>>(&body)
+        .map_err(|e| format!("docker API parse error: {e}"))?;
+    Ok(rows.into_iter().map(into_container).collect())
+}
+
+fn into_container(c: ApiContainer) -> DockerContainer {
+    let name = c
+        .names
+        .first()
+        .map(|n| n.trim_start_matches('/').to_string())
+        .unwrap_or_else(|| short_id(&c.id));
+
+    let ports = c
+        .ports
+        .iter()
+        .map(|p| match p.public_port {
+            Some(public) => format!(
+                "{}:{public}->{}/{}",
+                p.ip.as_deref().unwrap_or("0.0.0.0"),
+                p.private_port,
+                p.kind
+            ),
+            None => format!("{}/{}", p.private_port, p.kind),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let networks = c
+        .network_settings
+        .map(|n| {
+            n.networks
+                .into_iter()
+                .map(|(name, net)| {
+                    if net.ip_address.is_empty() {
+                        name
+                    } else {
+                        format!("{name} ({})", net.ip_address)
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mounts = c
+        .mounts
+        .iter()
+        .map(|m| {
+            let from = m.name.as_deref().unwrap_or(&m.source);
+            let mode = if m.rw { "rw" } else { "ro" };
+            format!("{} {from} -> {} ({mode})", m.kind, m.destination)
+        })
+        .collect();
+
+    DockerContainer {
+        id: short_id(&c.id),
+        name,
+        health: parse_health(&c.status),
+        exit_code: parse_exit_code(&c.status),
+        status: c.status,
+        state: c.state,
+        image: c.image,
+        ports,
+        created: DateTime::<Utc>::from_timestamp(c.created, 0),
+        labels: c.labels.unwrap_or_default(),
+        networks,
+        mounts,
+        stats: None,
+    }
+}
+
+/// `nerdctl ps` for runtimes without an API socket; no stats are available this way
+async fn list_containers_cli(kind: RuntimeKind) -> Result<Vec<DockerContainer>, String> {
+    let raw = run_cmd(
+        kind.name(),
+        ["ps", "-a", "--no-trunc", "--format", "{{json .}}"]
+            .map(String::from)
+            .to_vec(),
+    )
+    .await?;
+
+    Ok(raw
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .map(|row| {
+            let field = |key: &str| {
+                row.get(key)
+                    .and_then(Value::as_str)
+                    .unwrap_or("")
+                    .to_string()
+            };
+            let status = field("Status");
+            let labels = match row.get("Labels") {
+                Some(Value::Object(map)) => map
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.as_str().unwrap_or("").to_string()))
+                    .collect(),
+                Some(Value::String(raw)) => raw
+                    .split(',')
+                    .filter_map(|kv| kv.split_once('='))
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                _ => BTreeMap::new(),
+            };
+            let state = match field("State") {
+                s if !s.is_empty() => s.to_lowercase(),
+                _ => state_from_status(&status),
+            };
+            DockerContainer {
+                id: short_id(&field("ID")),
+                name: field("Names"),
+                state,
+                health: parse_health(&status),
+                exit_code: parse_exit_code(&status),
+                status,
+                image: field("Image"),
+                ports: field("Ports"),
+                created: parse_cli_time(&field("CreatedAt")),
+                labels,
+                networks: field("Networks")
+                    .split(',')
+                    .filter(|n| !n.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+                mounts: vec![],
+                stats: None,
+            }
+        })
+        .collect())
+}
+
+/// CLI timestamps are RFC 3339 or Go's `2024-05-01 10:00:00 +0000 UTC`
+fn parse_cli_time(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw)
+        .or_else(|_| {
+            let trimmed = raw.rsplit_once(' ').map_or(raw, |(head, _)| head);
+            DateTime::parse_from_str(trimmed, "%Y-%m-%d %H:%M:%S %z")
+        })
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Derive an engine-style state from a CLI status like `Up 2 hours (Paused)`
+fn state_from_status(status: &str) -> String {
+    let lower = status.to_lowercase();
+    if lower.starts_with("up") {
+        if lower.contains("paused") {
+            "paused"
+        } else {
+            "running"
+        }
+    } else if lower.starts_with("exited") {
+        "exited"
+    } else if lower.starts_with("restarting") {
+        "restarting"
+    } else if lower.starts_with("created") {
+        "created"
+    } else {
+        return lower
+            .split_whitespace()
+            .next()
+            .unwrap_or("unknown")
+            .to_string();
+    }
+    .to_string()
+}
+
+fn short_id(id: &str) -> String {
+    id.chars().take(12).collect()
+}
+
+/// `Up 3 hours (healthy)` / `Up 5 seconds (health: starting)`
+fn parse_health(status: &str) -> Option<String> {
+    let inner = status.rsplit_once('(')?.1.strip_suffix(')')?;
+    let health = inner.strip_prefix("health: ").unwrap_or(inner);
+    matches!(health, "healthy" | "unhealthy" | "starting").then(|| health.to_string())
+}
+
+/// `Exited (137) 2 minutes ago`
+fn parse_exit_code(status: &str) -> Option<i32> {
+    let rest = status.strip_prefix("Exited (")?;
+    rest.split_once(')')?.0.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::UnixListener;
+    use tokio::task::JoinHandle;
+
+    use super::*;
+
+    const CONTAINERS: &str = r#"[
+      {"Id": "4f1c2a9d8e7b6a5f4e3d2c1b0a99887766554433221100ffeeddccbbaa998877",
+       "Names": ["/web"], "Image": "nginx:1.27", "Created": 1760000000,
+       "State": "running", "Status": "Up 3 hours (healthy)",
+       "Ports": [{"IP": "0.0.0.0", "PrivatePort": 80, "PublicPort": 8080, "Type": "tcp"},
+                 {"PrivatePort": 443, "Type": "tcp"}],
+       "Labels": {"com.docker.compose.project": "shop", "com.docker.compose.service": "web"},
+       "NetworkSettings": {"Networks": {"shop_default": {"IPAddress": "172.18.0.2"}, "none": {"IPAddress": ""}}},
+       "Mounts": [{"Type": "volume", "Name": "static", "Source": "/var/lib/docker/volumes/static/_data",
+                   "Destination": "/usr/share/nginx/html", "RW": true}]},
+      {"Id": "9a8b7c6d5e4f", "Names": ["/worker"], "Image": "shop/worker",
+       "State": "exited", "Status": "Exited (137) 2 minutes ago", "Labels": null,
+       "Mounts": [{"Type": "bind", "Source": "/srv/worker.toml", "Destination": "/etc/worker.toml", "RW": false}]}
+    ]"#;
+
+    /// Answer one request on a fresh socket with `reply`; the task yields the request head
+    fn serve_once(name: &str, reply: Vec<u8>) -> (Endpoint, JoinHandle<String>) {
+        let path = std::env::temp_dir().join(format!("devdash-{}-{name}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        let socket = path.clone();
+        let server = tokio::spawn(async move {
+            let (mut conn, _) = listener.accept().await.unwrap();
+            let _ = std::fs::remove_file(&socket);
+            let mut head = Vec::new();
+            let mut byte = [0; 1];
+            while !head.ends_with(b"\r\n\r\n") {
+                conn.read_exact(&mut byte).await.unwrap();
+                head.push(byte[0]);
+            }
+            conn.write_all(&reply).await.unwrap();
+            String::from_utf8(head).unwrap()
+        });
+        (Endpoint::Unix(path), server)
+    }
+
+    fn content_length_reply(status: &str, body: &str) -> Vec<u8> {
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .into_bytes()
+    }
+
+    fn assert_containers(items: &[DockerContainer]) {
+        let [web, worker] = items else {
+            panic!("expected two containers, got {items:?}");
+        };
+        assert_eq!(web.id, "4f1c2a9d8e7b");
+        assert_eq!(web.name, "web");
+        assert_eq!(web.state, "running");
+        assert_eq!(web.health.as_deref(), Some("healthy"));
+        assert_eq!(web.exit_code, None);
+        assert_eq!(web.ports, "0.0.0.0:8080->80/tcp, 443/tcp");
+        assert_eq!(web.created, DateTime::<Utc>::from_timestamp(1760000000, 0));
+        assert_eq!(
+            web.labels
+                .get("com.docker.compose.project")
+                .map(String::as_str),
+            Some("shop")
+        );
+        assert_eq!(web.networks, ["none", "shop_default (172.18.0.2)"]);
+        assert_eq!(web.mounts, ["volume static -> /usr/share/nginx/html (rw)"]);
+
+        assert_eq!(worker.name, "worker");
+        assert_eq!(worker.state, "exited");
+        assert_eq!(worker.health, None);
+        assert_eq!(worker.exit_code, Some(137));
+        assert!(worker.labels.is_empty());
+        assert!(worker.networks.is_empty());
+        assert_eq!(
+            worker.mounts,
+            ["bind /srv/worker.toml -> /etc/worker.toml (ro)"]
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_containers_from_chunked_reply() {
+        let mut reply =
+            b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n"
+                .to_vec();
+        for (i, piece) in CONTAINERS.as_bytes().chunks(100).enumerate() {
+            // Chunk extensions are allowed after the size and must be ignored
+            let ext = if i == 1 { ";name=value" } else { "" };
+            reply.extend_from_slice(format!("{:x}{ext}\r\n", piece.len()).as_bytes());
+            reply.extend_from_slice(piece);
+            reply.extend_from_slice(b"\r\n");
+        }
+        reply.extend_from_slice(b"0\r\n\r\n");
+
+        let (endpoint, server) = serve_once("chunked", reply);
+        let items = list_containers(&endpoint).await.unwrap();
+        let head = server.await.unwrap();
+        assert!(head.starts_with("GET /containers/json?all=1 HTTP/1.1\r\n"));
+        assert_containers(&items);
+    }
+
+    #[tokio::test]
+    async fn lists_containers_from_content_length_reply() {
+        let (endpoint, server) =
+            serve_once("content-length", content_length_reply("200 OK", CONTAINERS));
+        let items = list_containers(&endpoint).await.unwrap();
+        server.await.unwrap();
+        assert_containers(&items);
+    }
+
+    #[tokio::test]
+    async fn surfaces_daemon_error_message() {
+        let (endpoint, server) = serve_once(
+            "not-found",
+            content_length_reply("404 Not Found", r#"{"message": "page not found"}"#),
+        );
+        let err = list_containers(&endpoint).await.unwrap_err();
+        server.await.unwrap();
+        assert_eq!(err, "page not found");
+    }
+
+    #[test]
+    fn parses_status_details() {
+        assert_eq!(
+            parse_health("Up 5 seconds (health: starting)").as_deref(),
+            Some("starting")
+        );
+        assert_eq!(
+            parse_health("Up 1 hour (unhealthy)").as_deref(),
+            Some("unhealthy")
+        );
+        assert_eq!(parse_health("Up 2 hours (Paused)"), None);
+        assert_eq!(parse_exit_code("Exited (0) 1 second ago"), Some(0));
+        assert_eq!(parse_exit_code("Up 2 hours"), None);
+    }
+}
@@ -0,0 +1,307 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpStream, UnixStream};
+
+use crate::config::DockerConfig;
+
+const DEFAULT_SOCKET: &str = "/var/run/docker.sock";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Where the Docker Engine API is listening
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Endpoint {
+    Unix(PathBuf),
+    Tcp(String),
+}
+
+impl Endpoint {
+    /// Resolve from `[docker] host`, then `DOCKER_HOST`, then the default socket
+    pub fn resolve(cfg: &DockerConfig) -> Result<Self, String> {
+        if let Some(host) = cfg.host.as_deref().filter(|h| !h.trim().is_empty()) {
+            return Self::parse(host);
+        }
+        match std::env::var("DOCKER_HOST") {
+            Ok(host) if !host.trim().is_empty() => Self::parse(&host),
+            _ => Ok(Endpoint::Unix(PathBuf::from(DEFAULT_SOCKET))),
+        }
+    }
+
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let raw = raw.trim();
+        if let Some(path) = raw.strip_prefix("unix://") {
+            return Ok(Endpoint::Unix(PathBuf::from(path)));
+        }
+        if raw.starts_with('/') {
+            return Ok(Endpoint::Unix(PathBuf::from(raw)));
+        }
+        if let Some(addr) = raw
+            .strip_prefix("tcp://")
+            .or_else(|| raw.strip_prefix("http://"))
+        {
+            return Ok(Endpoint::Tcp(addr.trim_end_matches('/').to_string()));
+        }
+        Err(format!(
+            "unsupported docker host '{raw}' (expected unix:// or tcp://)"
+        ))
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Endpoint::Unix(path) => format!("unix://{}", path.display()),
+            Endpoint::Tcp(addr) => format!("tcp://{addr}"),
+        }
+    }
+}
+
+trait Conn: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Conn for T {}
+
+enum BodyKind {
+    Chunked,
+    Length(u64),
+    UntilClose,
+}
+
+/// An HTTP/1.1 response whose body can be consumed incrementally
+pub struct Response {
+    pub status: u16,
+    reader: BufReader<Box<dyn Conn>>,
+    kind: BodyKind,
+    done: bool,
+}
+
+impl Response {
+    /// Next slice of the decoded body, `None` once the body is complete
+    pub async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, String> {
+        if self.done {
+            return Ok(None);
+        }
+        let chunk = match &mut self.kind {
+            BodyKind::Chunked => {
+                let mut size_line = String::new();
+                self.reader
+                    .read_line(&mut size_line)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let size_hex = size_line.trim().split(';').next().unwrap_or("");
+                let size = usize::from_str_radix(size_hex, 16)
+                    .map_err(|_| format!("bad chunk size '{}'", size_line.trim()))?;
+                if size == 0 {
+                    self.done = true;
+                    return Ok(None);
+                }
+                let mut buf = vec![0; size + 2];
+                self.reader
+                    .read_exact(&mut buf)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                buf.truncate(size);
+                buf
+            }
+            BodyKind::Length(remaining) => {
+                if *remaining == 0 {
+                    self.done = true;
+                    return Ok(None);
+                }
+                let mut buf = vec![0; (*remaining).min(64 * 1024) as usize];
+                let n = self
+                    .reader
+                    .read(&mut buf)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                if n == 0 {
+                    return Err("connection closed mid-body".to_string());
+                }
+                *remaining -= n as u64;
+                buf.truncate(n);
+                buf
+            }
+            BodyKind::UntilClose => {
+                let mut buf = vec![0; 64 * 1024];
+                let n = self
+                    .reader
+                    .read(&mut buf)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                if n == 0 {
+                    self.done = true;
+                    return Ok(None);
+                }
+                buf.truncate(n);
+                buf
+            }
+        };
+        Ok(Some(chunk))
+    }
+
+    pub async fn read_all(mut self) -> Result<Vec<u8>, String> {
+        let mut body = Vec::new();
+        while let Some(chunk) = self.next_chunk().await? {
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+}
+
+/// Send a request and return once the response headers are read
+pub async fn open(
+    endpoint: &Endpoint,
+    method: &str,
+    path: &str,
+    body: Option<&[u8]>,
+) -> Result<Response, String> {
+    let mut conn: Box<dyn Conn> = match endpoint {
+        Endpoint::Unix(path) => Box::new(
+            UnixStream::connect(path)
+                .await
+                .map_err(|e| connect_error(endpoint, e))?,
+        ),
+        Endpoint::Tcp(addr) => Box::new(
+            TcpStream::connect(addr)
+                .await
+                .map_err(|e| connect_error(endpoint, e))?,
+        ),
+    };
+
+    let mut head = format!("{method} {path} HTTP/1.1\r\nHost: docker\r\nConnection: close\r\n");
+    if let Some(body) = body {
+        head.push_str("Content-Type: application/json\r\n");
+        head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    head.push_str("\r\n");
+    conn.write_all(head.as_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Some(body) = body {
+        conn.write_all(body).await.map_err(|e| e.to_string())?;
+    }
+
+    let mut reader = BufReader::new(conn);
+    let mut status_line = String::new();
+    reader
+        .read_line(&mut status_line)
+        .await
+        .map_err(|e| e.to_string())?;
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|s| s.parse::<u16>().ok())
+        .ok_or_else(|| format!("bad response from {}", endpoint.describe()))?;
+
+    let mut kind = BodyKind::UntilClose;
+    loop {
+        let mut line = String::new();
+        let n = reader
+            .read_line(&mut line)
+            .await
+            .map_err(|e| e.to_string())?;
+        let line = line.trim_end();
+        if n == 0 || line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("transfer-encoding") && value.eq_ignore_ascii_case("chunked") {
+            kind = BodyKind::Chunked;
+        } else if name.eq_ignore_ascii_case("content-length")
+            && !matches!(kind, BodyKind::Chunked)
+            && let Ok(len) = value.parse()
+        {
+            kind = BodyKind::Length(len);
+        }
+    }
+
+    Ok(Response {
+        status,
+        reader,
+        kind,
+        done: false,
+    })
+}
+
+/// Perform a request and return the full body, mapping non-2xx replies to the daemon's message
+pub async fn request(
+    endpoint: &Endpoint,
+    method: &str,
+    path: &str,
+    body: Option<&[u8]>,
+) -> Result<Vec<u8>, String> {
+    let exchange = async {
+        let response = open(endpoint, method, path, body).await?;
+        let status = response.status;
+        let body = response.read_all().await?;
+        if (200..300).contains(&status) {
+            Ok(body)
+        } else {
+            Err(api_error(status, &body))
+        }
+    };
+    tokio::time::timeout(REQUEST_TIMEOUT, exchange)
+        .await
+        .map_err(|_| format!("{} timed out", endpoint.describe()))?
+}
+
+/// Daemon errors come back as `{"message": "..."}`
+pub fn api_error(status: u16, body: &[u8]) -> String {
+    serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| v.get("message")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| format!("HTTP {status}"))
+}
+
+fn connect_error(endpoint: &Endpoint, e: std::io::Error) -> String {
+    match e.kind() {
+        std::io::ErrorKind::NotFound => {
+            format!("socket not found at {}", endpoint.describe())
+        }
+        std::io::ErrorKind::PermissionDenied => format!(
+            "permission denied on {} (check the socket's group membership)",
+            endpoint.describe()
+        ),
+        std::io::ErrorKind::ConnectionRefused => {
+            format!("daemon not reachable at {}", endpoint.describe())
+        }
+        _ => format!("{}: {e}", endpoint.describe()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_endpoints() {
+        assert_eq!(
+            Endpoint::parse("unix:///run/user/1000/podman/podman.sock"),
+            Ok(Endpoint::Unix(PathBuf::from(
+                "/run/user/1000/podman/podman.sock"
+            )))
+        );
+        assert_eq!(
+            Endpoint::parse(" /var/run/docker.sock "),
+            Ok(Endpoint::Unix(PathBuf::from("/var/run/docker.sock")))
+        );
+        assert_eq!(
+            Endpoint::parse("tcp://10.0.0.5:2375/"),
+            Ok(Endpoint::Tcp("10.0.0.5:2375".to_string()))
+        );
+        assert_eq!(
+            Endpoint::parse("http://localhost:2375"),
+            Ok(Endpoint::Tcp("localhost:2375".to_string()))
+        );
+        assert!(Endpoint::parse("ssh://me@build-box").is_err());
+    }
+
+    #[test]
+    fn maps_error_bodies() {
+        assert_eq!(
+            api_error(404, br#"{"message": "No such container: web"}"#),
+            "No such container: web"
+        );
+        assert_eq!(api_error(502, b"<html>Bad Gateway</html>"), "HTTP 502");
+    }
+}
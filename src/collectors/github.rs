@@ -0,0 +1,555 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::config::{Config, GitHubConfig};
+
+use super::gather::run_cmd_in;
+use super::types::{PrItem, PrStatus, PrView};
+
+/// The fields each PR row needs: review, CI and merge state
+const PR_FRAGMENT: &str = r#"fragment Pr on PullRequest {
+  number title url createdAt updatedAt body isDraft reviewDecision mergeable additions deletions
+  author { login }
+  repository { nameWithOwner }
+  labels(first: 10) { nodes { name } }
+  reviewRequests(first: 10) {
+    nodes { requestedReviewer { ... on User { login } ... on Team { name } ... on Bot { login } } }
+  }
+  commits(last: 1) { nodes { commit { statusCheckRollup { state } } } }
+}"#;
+
+#[derive(Debug, Deserialize)]
+struct GraphQlReply {
+    /// Searches keyed by their `v<N>` alias
+    data: Option<HashMap<String, Search>>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Search {
+    issue_count: u64,
+    nodes: Vec<PullNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Nodes<T> {
+    nodes: Vec<T>,
+}
+
+impl<T> Default for Nodes<T> {
+    fn default() -> Self {
+        Self { nodes: Vec::new() }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct PullNode {
+    number: u64,
+    title: String,
+    url: String,
+    created_at: String,
+    updated_at: String,
+    body: Option<String>,
+    is_draft: bool,
+    review_decision: Option<String>,
+    mergeable: String,
+    additions: u64,
+    deletions: u64,
+    /// Null for deleted accounts
+    author: Option<Login>,
+    repository: Option<Repository>,
+    labels: Nodes<Label>,
+    review_requests: Nodes<ReviewRequest>,
+    commits: Nodes<CommitNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Login {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Repository {
+    name_with_owner: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Label {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewRequest {
+    requested_reviewer: Option<Reviewer>,
+}
+
+/// A user or bot has a `login`, a team a `name`
+#[derive(Debug, Deserialize)]
+struct Reviewer {
+    login: Option<String>,
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitNode {
+    commit: Commit,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Commit {
+    status_check_rollup: Option<Rollup>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Rollup {
+    state: String,
+}
+
+pub async fn collect_prs(cfg: &Config) -> PrStatus {
+    let token = std::env::var(&cfg.github.token_env)
+        .ok()
+        .filter(|t| !t.trim().is_empty());
+    let mut scopes = search_scopes(&cfg.github);
+    // GraphQL needs a token; without one (or a scope) `gh` answers with its own login
+    let mut api_error = None;
+    if let (false, Some(token)) = (scopes.is_empty(), token) {
+        let queries = search_queries(cfg, &scopes);
+        match collect_prs_github_api(&cfg.github.api_url, &queries, &token).await {
+            Ok(views) => return pr_status(views, format!("github-api ({})", cfg.github.token_env)),
+            Err(e) => api_error = Some(e),
+        }
+    }
+
+    // `{owner}/{repo}` is filled in by gh from the checkout's remote
+    if scopes.is_empty() {
+        scopes.push("repo:{owner}/{repo}".to_string());
+    }
+    match collect_prs_via_gh(cfg, &search_queries(cfg, &scopes)).await {
+        Ok(views) => pr_status(views, "gh-cli".to_string()),
+        Err(e) => PrStatus {
+            source: "none".to_string(),
+            error: Some(match api_error {
+                Some(api) => format!("GitHub API failed ({api}) and so did gh ({e})"),
+                None => format!(
+                    "PR auth/setup needed. Configure github.repo + {} or run `gh auth login` ({e})",
+                    cfg.github.token_env
+                ),
+            }),
+            ..PrStatus::default()
+        },
+    }
+}
+
+/// `repo:` per configured repo, plus the free-form `query` (e.g. `org:acme`)
+fn search_scopes(cfg: &GitHubConfig) -> Vec<String> {
+    let repos = if cfg.repos.is_empty() {
+        cfg.repo.iter().cloned().collect()
+    } else {
+        cfg.repos.clone()
+    };
+    let mut scopes = repos
+        .iter()
+        .map(|repo| format!("repo:{repo}"))
+        .collect::<Vec<_>>();
+    scopes.extend(cfg.query.clone().filter(|q| !q.trim().is_empty()));
+    scopes
+}
+
+/// `(view name, search query)` per scope for `all` and every configured filter; scopes are
+/// searched separately so a query's own qualifiers don't leak onto the repos
+fn search_queries(cfg: &Config, scopes: &[String]) -> Vec<(String, String)> {
+    let mut queries = Vec::new();
+    for scope in scopes {
+        queries.push((
+            "all".to_string(),
+            format!("{scope} is:pr is:open sort:updated-desc"),
+        ));
+    }
+    for filter in &cfg.github.filters {
+        let mut qualifiers = String::new();
+        for (on, qualifier) in [
+            (filter.mine, "author:@me"),
+            (filter.review_requested, "review-requested:@me"),
+            (filter.assigned, "assignee:@me"),
+            (filter.exclude_drafts, "draft:false"),
+        ] {
+            if on {
+                qualifiers.push(' ');
+                qualifiers.push_str(qualifier);
+            }
+        }
+        for label in &filter.labels {
+            qualifiers.push_str(&format!(" label:\"{}\"", label.replace('"', "")));
+        }
+        for scope in scopes {
+            queries.push((
+                filter.name.clone(),
+                format!("{scope} is:pr is:open sort:updated-desc{qualifiers}"),
+            ));
+        }
+    }
+    queries
+}
+
+/// One aliased search per query (`v0: search(query: $q0 ...)`) so a single call returns them all
+fn graphql_document(views: usize) -> String {
+    let params = (0..views)
+        .map(|i| format!("$q{i}: String!"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let searches = (0..views)
+        .map(|i| {
+            format!("  v{i}: search(query: $q{i}, type: ISSUE, first: 10) {{ issueCount nodes {{ ...Pr }} }}\n")
+        })
+        .collect::<String>();
+    format!("query({params}) {{\n{searches}}}\n{PR_FRAGMENT}")
+}
+
+fn pr_status(views: Vec<PrView>, source: String) -> PrStatus {
+    PrStatus {
+        views,
+        source,
+        error: None,
+    }
+}
+
+/// GraphQL lives beside the REST base: `api.github.com/graphql`, but `<host>/api/graphql`
+/// on GitHub Enterprise Server, whose REST base is `<host>/api/v3`
+fn graphql_url(api_url: &str) -> String {
+    let base = api_url.trim_end_matches('/');
+    match base.strip_suffix("/v3") {
+        Some(api) => format!("{api}/graphql"),
+        None => format!("{base}/graphql"),
+    }
+}
+
+/// The `gh --hostname` matching `api_url`; `None` for github.com, gh's default
+fn gh_hostname(api_url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(api_url).ok()?;
+    let host = url.host_str()?;
+    // `api.<host>` serves github.com and GHE.com tenants, gh knows them by `<host>`
+    let host = host.strip_prefix("api.").unwrap_or(host);
+    if host == "github.com" {
+        return None;
+    }
+    Some(match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    })
+}
+
+async fn collect_prs_github_api(
+    api_url: &str,
+    queries: &[(String, String)],
+    token: &str,
+) -> Result<Vec<PrView>, String> {
+    let variables = queries
+        .iter()
+        .enumerate()
+        .map(|(i, (_, q))| (format!("q{i}"), json!(q)))
+        .collect::<serde_json::Map<_, _>>();
+    let response = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| e.to_string())?
+        .post(graphql_url(api_url))
+        .header("User-Agent", "devdash")
+        .bearer_auth(token)
+        .json(&json!({ "query": graphql_document(queries.len()), "variables": variables }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("GitHub API {}", response.status()));
+    }
+    let raw = response.text().await.map_err(|e| e.to_string())?;
+    parse_reply(&raw, queries)
+}
+
+/// The same query through `gh api graphql`, so the rows match the API path exactly
+async fn collect_prs_via_gh(
+    cfg: &Config,
+    queries: &[(String, String)],
+) -> Result<Vec<PrView>, String> {
+    let mut args = vec!["api".to_string(), "graphql".to_string()];
+    if let Some(hostname) = gh_hostname(&cfg.github.api_url) {
+        args.push("--hostname".to_string());
+        args.push(hostname);
+    }
+    args.extend([
+        "-f".to_string(),
+        format!("query={}", graphql_document(queries.len())),
+    ]);
+    // -F fills in the {owner}/{repo} placeholders; -f would send them verbatim
+    for (i, (_, query)) in queries.iter().enumerate() {
+        args.push("-F".to_string());
+        args.push(format!("q{i}={query}"));
+    }
+    let raw = run_cmd_in("gh", args, Some(&cfg.repo_path)).await?;
+    parse_reply(&raw, queries)
+}
+
+/// Merge each view's per-scope searches, newest first; a PR found by two scopes counts once
+fn parse_reply(raw: &str, queries: &[(String, String)]) -> Result<Vec<PrView>, String> {
+    let reply: GraphQlReply =
+        serde_json::from_str(raw).map_err(|e| format!("GitHub parse error: {e}"))?;
+    if let Some(err) = reply.errors.first() {
+        return Err(format!("GitHub GraphQL: {}", err.message));
+    }
+    let mut data = reply.data.ok_or("GitHub reply has no data")?;
+    let mut views: Vec<PrView> = Vec::new();
+    for (i, (name, _)) in queries.iter().enumerate() {
+        let search = data
+            .remove(&format!("v{i}"))
+            .ok_or_else(|| format!("GitHub reply lacks the '{name}' search"))?;
+        let idx = match views.iter().position(|v| v.name == *name) {
+            Some(idx) => idx,
+            None => {
+                views.push(PrView {
+                    name: name.clone(),
+                    ..PrView::default()
+                });
+                views.len() - 1
+            }
+        };
+        let view = &mut views[idx];
+        view.total += search.issue_count;
+        // Non-PR search hits come back as empty objects
+        for pr in search.nodes.into_iter().filter(|pr| pr.number > 0) {
+            if view.items.iter().any(|seen| seen.url == pr.url) {
+                view.total = view.total.saturating_sub(1);
+            } else {
+                view.items.push(pr_item(pr));
+            }
+        }
+    }
+    for view in &mut views {
+        view.items.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    }
+    Ok(views)
+}
+
+fn pr_item(pr: PullNode) -> PrItem {
+    let checks = pr
+        .commits
+        .nodes
+        .into_iter()
+        .next()
+        .and_then(|c| c.commit.status_check_rollup)
+        .map(|r| {
+            match r.state.as_str() {
+                "SUCCESS" => "success",
+                "FAILURE" | "ERROR" => "failure",
+                _ => "pending",
+            }
+            .to_string()
+        });
+    PrItem {
+        number: pr.number,
+        title: pr.title,
+        author: pr.author.map_or_else(|| "ghost".to_string(), |a| a.login),
+        url: pr.url,
+        repo: pr.repository.map(|r| r.name_with_owner).unwrap_or_default(),
+        created_at: pr.created_at,
+        updated_at: pr.updated_at,
+        body: pr.body.filter(|b| !b.trim().is_empty()),
+        draft: pr.is_draft,
+        review_decision: pr
+            .review_decision
+            .map(|d| d.to_lowercase().replace('_', " ")),
+        requested_reviewers: pr
+            .review_requests
+            .nodes
+            .into_iter()
+            .filter_map(|r| {
+                let reviewer = r.requested_reviewer?;
+                reviewer.login.or(reviewer.name)
+            })
+            .collect(),
+        checks,
+        labels: pr.labels.nodes.into_iter().map(|l| l.name).collect(),
+        mergeable: pr.mergeable.to_lowercase(),
+        additions: pr.additions,
+        deletions: pr.deletions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    #[test]
+    fn derives_graphql_url() {
+        assert_eq!(
+            graphql_url("https://api.github.com"),
+            "https://api.github.com/graphql"
+        );
+        assert_eq!(
+            graphql_url("https://ghe.corp/api/v3"),
+            "https://ghe.corp/api/graphql"
+        );
+        assert_eq!(
+            graphql_url("https://ghe.corp/api/v3/"),
+            "https://ghe.corp/api/graphql"
+        );
+        assert_eq!(
+            graphql_url("http://127.0.0.1:4000/"),
+            "http://127.0.0.1:4000/graphql"
+        );
+    }
+
+    #[test]
+    fn derives_gh_hostname() {
+        assert_eq!(gh_hostname("https://api.github.com"), None);
+        assert_eq!(gh_hostname("https://api.github.com/"), None);
+        assert_eq!(
+            gh_hostname("https://ghe.corp/api/v3").as_deref(),
+            Some("ghe.corp")
+        );
+        assert_eq!(
+            gh_hostname("https://ghe.corp:8443/api/v3").as_deref(),
+            Some("ghe.corp:8443")
+        );
+        assert_eq!(gh_hostname("not a url"), None);
+    }
+
+    /// Answer one GraphQL POST with `reply`; the task yields the request head and body
+    async fn serve_once(
+        reply: serde_json::Value,
+    ) -> (String, tokio::task::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (conn, _) = listener.accept().await.unwrap();
+            let mut conn = BufReader::new(conn);
+            let mut head = String::new();
+            while !head.ends_with("\r\n\r\n") {
+                conn.read_line(&mut head).await.unwrap();
+            }
+            let length = head
+                .lines()
+                .find_map(|l| {
+                    let (name, value) = l.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().ok())?
+                })
+                .unwrap();
+            let mut body = vec![0; length];
+            conn.read_exact(&mut body).await.unwrap();
+            let reply = reply.to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{reply}",
+                reply.len()
+            );
+            conn.get_mut().write_all(response.as_bytes()).await.unwrap();
+            (head, String::from_utf8(body).unwrap())
+        });
+        (format!("http://{addr}/api/v3"), server)
+    }
+
+    #[tokio::test]
+    async fn queries_configured_api_url() {
+        let approved = json!({
+            "number": 42, "title": "Add GraphQL PR collector", "url": "https://ghe.corp/acme/api/pull/42",
+            "createdAt": "2026-10-01T08:00:00Z", "updatedAt": "2026-10-18T08:00:00Z",
+            "body": "Switches to GraphQL.", "isDraft": false, "reviewDecision": "APPROVED",
+            "mergeable": "MERGEABLE", "additions": 120, "deletions": 14,
+            "author": {"login": "alice"}, "repository": {"nameWithOwner": "acme/api"},
+            "labels": {"nodes": [{"name": "enhancement"}]},
+            "reviewRequests": {"nodes": []},
+            "commits": {"nodes": [{"commit": {"statusCheckRollup": {"state": "SUCCESS"}}}]}
+        });
+        let blocked = json!({
+            "number": 7, "title": "Bump serde", "url": "https://ghe.corp/acme/api/pull/7",
+            "createdAt": "2026-09-01T08:00:00Z", "updatedAt": "2026-10-17T08:00:00Z",
+            "body": " ", "isDraft": true, "reviewDecision": "CHANGES_REQUESTED",
+            "mergeable": "CONFLICTING", "additions": 1, "deletions": 1,
+            "author": null, "repository": {"nameWithOwner": "acme/api"},
+            "labels": {"nodes": []},
+            "reviewRequests": {"nodes": [
+                {"requestedReviewer": {"login": "bob"}},
+                {"requestedReviewer": {"name": "platform"}}
+            ]},
+            "commits": {"nodes": [{"commit": {"statusCheckRollup": {"state": "ERROR"}}}]}
+        });
+        let (api_url, server) = serve_once(json!({"data": {
+            "v0": {"issueCount": 2, "nodes": [blocked, approved]},
+            "v1": {"issueCount": 1, "nodes": [approved]},
+            "v2": {"issueCount": 0, "nodes": []},
+            "v3": {"issueCount": 0, "nodes": [{}]}
+        }}))
+        .await;
+
+        let mut cfg = Config::default();
+        cfg.github.repo = Some("acme/api".to_string());
+        cfg.github.api_url = api_url;
+
+        let queries = search_queries(&cfg, &search_scopes(&cfg.github));
+        let views = collect_prs_github_api(&cfg.github.api_url, &queries, "ghp_test").await;
+        let prs = pr_status(views.unwrap(), "github-api".to_string());
+        let (head, body) = server.await.unwrap();
+        assert!(head.starts_with("POST /api/graphql HTTP/1.1\r\n"));
+        assert!(
+            head.to_lowercase()
+                .contains("authorization: bearer ghp_test\r\n")
+        );
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(
+            body["variables"]["q1"],
+            "repo:acme/api is:pr is:open sort:updated-desc author:@me"
+        );
+
+        let views = prs
+            .views
+            .iter()
+            .map(|v| (v.name.as_str(), v.total, v.items.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            views,
+            [
+                ("all", 2, 2),
+                ("mine", 1, 1),
+                ("review", 0, 0),
+                ("assigned", 0, 0)
+            ]
+        );
+
+        let [newest, oldest] = prs.items(0) else {
+            panic!("expected two PRs");
+        };
+        assert_eq!(newest.number, 42);
+        assert_eq!(newest.author, "alice");
+        assert_eq!(newest.repo, "acme/api");
+        assert_eq!(newest.checks.as_deref(), Some("success"));
+        assert_eq!(newest.review_decision.as_deref(), Some("approved"));
+        assert_eq!(newest.mergeable, "mergeable");
+        assert_eq!(newest.labels, ["enhancement"]);
+        assert_eq!((newest.additions, newest.deletions), (120, 14));
+
+        assert_eq!(oldest.number, 7);
+        assert_eq!(oldest.author, "ghost");
+        assert!(oldest.draft);
+        assert_eq!(oldest.body, None);
+        assert_eq!(oldest.checks.as_deref(), Some("failure"));
+        assert_eq!(oldest.review_decision.as_deref(), Some("changes requested"));
+        assert_eq!(oldest.mergeable, "conflicting");
+        assert_eq!(oldest.requested_reviewers, ["bob", "platform"]);
+    }
+}
@@ -1,7 +1,20 @@
+mod aws;
 mod cache;
+mod docker;
+mod engine;
 mod gather;
+mod github;
+mod ports;
+mod pressure;
+mod runtime;
+mod storage;
+mod systemd;
 mod types;
 
+pub use aws::instance_action;
 pub use cache::{DataCache, apply_cache};
-pub use gather::collect_all;
+pub use docker::{container_action, follow_container_logs, follow_docker_events};
+pub use gather::{collect_all, run_cmd, run_cmd_in};
+pub use runtime::Runtime;
+pub use storage::prune;
 pub use types::*;
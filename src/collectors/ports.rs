@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::fs;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// A socket read from `/proc/net/*` before it is joined with the process table
+pub struct RawSocket {
+    pub protocol: &'static str,
+    pub local_addr: String,
+    pub port: u16,
+    pub inode: u64,
+}
+
+/// TCP sockets in LISTEN and unconnected UDP sockets from `/proc/net/{tcp,tcp6,udp,udp6}`
+pub fn read_listening_sockets() -> Result<Vec<RawSocket>, String> {
+    let mut out = Vec::new();
+    let mut readable = false;
+
+    for (protocol, path) in [
+        ("tcp", "/proc/net/tcp"),
+        ("tcp6", "/proc/net/tcp6"),
+        ("udp", "/proc/net/udp"),
+        ("udp6", "/proc/net/udp6"),
+    ] {
+        let Ok(raw) = fs::read_to_string(path) else {
+            continue;
+        };
+        readable = true;
+        let is_tcp = protocol.starts_with("tcp");
+
+        for line in raw.lines().skip(1) {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            if fields.len() < 10 {
+                continue;
+            }
+            // 0A = TCP_LISTEN; 07 = TCP_CLOSE, which is how bound UDP sockets are reported
+            let listening = if is_tcp {
+                fields[3] == "0A"
+            } else {
+                fields[3] == "07"
+            };
+            if !listening {
+                continue;
+            }
+
+            let Some((local_addr, port)) = parse_hex_endpoint(fields[1]) else {
+                continue;
+            };
+            out.push(RawSocket {
+                protocol,
+                local_addr,
+                port,
+                inode: fields[9].parse().unwrap_or(0),
+            });
+        }
+    }
+
+    if !readable {
+        return Err("/proc/net not available on this platform".to_string());
+    }
+
+    out.sort_by(|a, b| a.port.cmp(&b.port).then(a.protocol.cmp(b.protocol)));
+    out.dedup_by(|a, b| a.port == b.port && a.protocol == b.protocol && a.inode == b.inode);
+    Ok(out)
+}
+
+/// Map socket inodes to owning pids by scanning `/proc/<pid>/fd`.
+/// Processes owned by other users are silently skipped when unreadable.
+pub fn socket_owners() -> HashMap<u64, u32> {
+    let mut owners = HashMap::new();
+    let Ok(procs) = fs::read_dir("/proc") else {
+        return owners;
+    };
+
+    for entry in procs.flatten() {
+        let Some(pid) = entry
+            .file_name()
+            .to_str()
+            .and_then(|s| s.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let Ok(fds) = fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            let Ok(target) = fs::read_link(fd.path()) else {
+                continue;
+            };
+            let target = target.to_string_lossy();
+            if let Some(inode) = target
+                .strip_prefix("socket:[")
+                .and_then(|rest| rest.strip_suffix(']'))
+                .and_then(|v| v.parse::<u64>().ok())
+            {
+                owners.entry(inode).or_insert(pid);
+            }
+        }
+    }
+
+    owners
+}
+
+fn parse_hex_endpoint(raw: &str) -> Option<(String, u16)> {
+    let (addr_hex, port_hex) = raw.split_once(':')?;
+    let port = u16::from_str_radix(port_hex, 16).ok()?;
+
+    // Addresses are printed as native-endian 32-bit words
+    let addr = match addr_hex.len() {
+        8 => {
+            let word = u32::from_str_radix(addr_hex, 16).ok()?;
+            Ipv4Addr::from(word.to_ne_bytes()).to_string()
+        }
+        32 => {
+            let mut bytes = [0u8; 16];
+            for (i, chunk) in bytes.chunks_mut(4).enumerate() {
+                let word = u32::from_str_radix(&addr_hex[i * 8..i * 8 + 8], 16).ok()?;
+                chunk.copy_from_slice(&word.to_ne_bytes());
+            }
+            let v6 = Ipv6Addr::from(bytes);
+            match v6.to_ipv4_mapped() {
+                Some(v4) => v4.to_string(),
+                None => v6.to_string(),
+            }
+        }
+        _ => return None,
+    };
+
+    Some((addr, port))
+}
@@ -0,0 +1,129 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use super::types::{CgroupStatus, PressureStats, PsiAverages};
+
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+/// Previous cgroup CPU sample used to turn `usage_usec` into a rate
+#[derive(Debug, Default)]
+pub struct CgroupSampler {
+    prev: Option<(u64, Instant)>,
+}
+
+/// Read `/proc/pressure/{cpu,memory,io}`; `None` when PSI is unavailable
+pub fn read_pressure() -> Option<PressureStats> {
+    let stats = PressureStats {
+        cpu: read_psi_file(Path::new("/proc/pressure/cpu")),
+        memory: read_psi_file(Path::new("/proc/pressure/memory")),
+        io: read_psi_file(Path::new("/proc/pressure/io")),
+    };
+
+    if stats.cpu.is_none() && stats.memory.is_none() && stats.io.is_none() {
+        None
+    } else {
+        Some(stats)
+    }
+}
+
+fn read_psi_file(path: &Path) -> Option<PsiAverages> {
+    let raw = fs::read_to_string(path).ok()?;
+    // Only the "some" line is reported; "full" is absent for cpu on older kernels
+    let line = raw.lines().find(|l| l.starts_with("some "))?;
+    let mut out = PsiAverages::default();
+    for (key, value) in line.split_whitespace().filter_map(|f| f.split_once('=')) {
+        match key {
+            "avg10" => out.avg10 = value.parse().unwrap_or(0.0),
+            "avg60" => out.avg60 = value.parse().unwrap_or(0.0),
+            _ => {}
+        }
+    }
+    Some(out)
+}
+
+/// Read cgroup v2 limits for the current process, walking up to the root so
+/// limits set on a parent slice are honoured. Returns `None` when no memory
+/// or CPU limit applies (i.e. devdash is not running in a constrained cgroup).
+pub fn read_cgroup(sampler: &mut CgroupSampler) -> Option<CgroupStatus> {
+    let rel = current_cgroup_path()?;
+    let root = PathBuf::from(CGROUP_ROOT);
+    let leaf = root.join(rel.trim_start_matches('/'));
+    if !leaf.join("cgroup.controllers").exists() {
+        return None;
+    }
+
+    let mut memory_max_bytes: Option<u64> = None;
+    let mut cpu_quota_cores: Option<f64> = None;
+    let mut dir = leaf.clone();
+    loop {
+        if let Some(max) = read_memory_max(&dir) {
+            memory_max_bytes = Some(memory_max_bytes.map_or(max, |m| m.min(max)));
+        }
+        if let Some(cores) = read_cpu_max(&dir) {
+            cpu_quota_cores = Some(cpu_quota_cores.map_or(cores, |c| c.min(cores)));
+        }
+        if dir == root || !dir.pop() || !dir.starts_with(&root) {
+            break;
+        }
+    }
+
+    if memory_max_bytes.is_none() && cpu_quota_cores.is_none() {
+        return None;
+    }
+
+    let memory_current_bytes = read_u64(&leaf.join("memory.current")).unwrap_or(0);
+
+    let cpu_used_cores = read_cpu_usage_usec(&leaf).and_then(|usage| {
+        let now = Instant::now();
+        let rate = sampler.prev.and_then(|(prev_usage, prev_at)| {
+            let elapsed = now.duration_since(prev_at).as_micros() as f64;
+            (elapsed > 0.0 && usage >= prev_usage).then(|| (usage - prev_usage) as f64 / elapsed)
+        });
+        sampler.prev = Some((usage, now));
+        rate
+    });
+
+    Some(CgroupStatus {
+        memory_max_bytes,
+        memory_current_bytes,
+        cpu_quota_cores,
+        cpu_used_cores,
+    })
+}
+
+fn current_cgroup_path() -> Option<String> {
+    let raw = fs::read_to_string("/proc/self/cgroup").ok()?;
+    // cgroup v2 exposes a single unified entry: "0::/path"
+    raw.lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .map(|p| p.trim().to_string())
+}
+
+fn read_memory_max(dir: &Path) -> Option<u64> {
+    let raw = fs::read_to_string(dir.join("memory.max")).ok()?;
+    let raw = raw.trim();
+    if raw == "max" { None } else { raw.parse().ok() }
+}
+
+fn read_cpu_max(dir: &Path) -> Option<f64> {
+    let raw = fs::read_to_string(dir.join("cpu.max")).ok()?;
+    let mut parts = raw.split_whitespace();
+    let quota = parts.next()?;
+    let period = parts.next().and_then(|p| p.parse::<f64>().ok())?;
+    if quota == "max" || period <= 0.0 {
+        return None;
+    }
+    quota.parse::<f64>().ok().map(|q| q / period)
+}
+
+fn read_cpu_usage_usec(dir: &Path) -> Option<u64> {
+    let raw = fs::read_to_string(dir.join("cpu.stat")).ok()?;
+    raw.lines()
+        .find_map(|line| line.strip_prefix("usage_usec "))
+        .and_then(|v| v.trim().parse().ok())
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
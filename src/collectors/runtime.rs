@@ -0,0 +1,137 @@
+use std::path::{Path, PathBuf};
+
+use crate::config::DockerConfig;
+
+use super::engine::Endpoint;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeKind {
+    Docker,
+    Podman,
+    Nerdctl,
+}
+
+impl RuntimeKind {
+    /// Runtime name, which is also its CLI binary
+    pub fn name(self) -> &'static str {
+        match self {
+            RuntimeKind::Docker => "docker",
+            RuntimeKind::Podman => "podman",
+            RuntimeKind::Nerdctl => "nerdctl",
+        }
+    }
+}
+
+/// The container runtime in use; runtimes without a Docker-compatible API socket go through their CLI
+#[derive(Debug, Clone)]
+pub struct Runtime {
+    pub kind: RuntimeKind,
+    pub endpoint: Option<Endpoint>,
+}
+
+impl Runtime {
+    /// Resolve `[docker] runtime`: `auto`, `docker`, `podman`, `nerdctl`, or a socket path / URL
+    pub fn detect(cfg: &DockerConfig) -> Result<Self, String> {
+        let runtime = cfg.runtime.trim();
+        match runtime {
+            "" | "auto" => auto_detect(cfg),
+            "docker" => Ok(Runtime {
+                kind: RuntimeKind::Docker,
+                endpoint: Some(Endpoint::resolve(cfg)?),
+            }),
+            "podman" => Ok(Runtime {
+                kind: RuntimeKind::Podman,
+                endpoint: Some(podman_endpoint(cfg)?),
+            }),
+            "nerdctl" => Ok(Runtime {
+                kind: RuntimeKind::Nerdctl,
+                endpoint: None,
+            }),
+            socket => {
+                let endpoint = Endpoint::parse(socket)?;
+                let kind = if socket.contains("podman") {
+                    RuntimeKind::Podman
+                } else {
+                    RuntimeKind::Docker
+                };
+                Ok(Runtime {
+                    kind,
+                    endpoint: Some(endpoint),
+                })
+            }
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    pub fn describe(&self) -> String {
+        match &self.endpoint {
+            Some(endpoint) => format!("{} via {}", self.name(), endpoint.describe()),
+            None => format!("{} cli", self.name()),
+        }
+    }
+}
+
+/// Prefer an explicit host, then a live docker socket, then podman's, then nerdctl on PATH
+fn auto_detect(cfg: &DockerConfig) -> Result<Runtime, String> {
+    let explicit = cfg.host.as_deref().is_some_and(|h| !h.trim().is_empty())
+        || std::env::var("DOCKER_HOST").is_ok_and(|h| !h.trim().is_empty());
+    let docker = Endpoint::resolve(cfg)?;
+    if explicit || endpoint_exists(&docker) {
+        return Ok(Runtime {
+            kind: RuntimeKind::Docker,
+            endpoint: Some(docker),
+        });
+    }
+
+    if let Ok(podman) = podman_endpoint(cfg)
+        && endpoint_exists(&podman)
+    {
+        return Ok(Runtime {
+            kind: RuntimeKind::Podman,
+            endpoint: Some(podman),
+        });
+    }
+
+    if on_path("nerdctl") {
+        return Ok(Runtime {
+            kind: RuntimeKind::Nerdctl,
+            endpoint: None,
+        });
+    }
+
+    Err("no container runtime found (tried docker and podman sockets, nerdctl)".to_string())
+}
+
+fn podman_endpoint(cfg: &DockerConfig) -> Result<Endpoint, String> {
+    if let Some(host) = cfg.host.as_deref().filter(|h| !h.trim().is_empty()) {
+        return Endpoint::parse(host);
+    }
+    if let Ok(host) = std::env::var("CONTAINER_HOST")
+        && !host.trim().is_empty()
+    {
+        return Endpoint::parse(&host);
+    }
+
+    // Rootless socket first, then the system service
+    let rootless = std::env::var_os("XDG_RUNTIME_DIR")
+        .map(|dir| PathBuf::from(dir).join("podman/podman.sock"));
+    let rootful = PathBuf::from("/run/podman/podman.sock");
+    let path = rootless.filter(|p| p.exists()).unwrap_or(rootful);
+    Ok(Endpoint::Unix(path))
+}
+
+fn endpoint_exists(endpoint: &Endpoint) -> bool {
+    match endpoint {
+        Endpoint::Unix(path) => path.exists(),
+        Endpoint::Tcp(_) => true,
+    }
+}
+
+fn on_path(binary: &str) -> bool {
+    std::env::var_os("PATH").is_some_and(|paths| {
+        std::env::split_paths(&paths).any(|dir| Path::new(&dir).join(binary).is_file())
+    })
+}
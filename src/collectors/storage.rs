@@ -0,0 +1,279 @@
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::engine::{self, Endpoint};
+use super::runtime::Runtime;
+use super::types::{DiskUsage, DockerImage, DockerStorage, DockerVolume};
+
+/// `/system/df` walks every layer and volume, so it is refreshed far less often than containers
+const STORAGE_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+struct ApiDiskUsage {
+    #[serde(default)]
+    layers_size: i64,
+    #[serde(default)]
+    images: Option<Vec<ApiImage>>,
+    #[serde(default)]
+    containers: Option<Vec<ApiDfContainer>>,
+    #[serde(default)]
+    volumes: Option<Vec<ApiVolume>>,
+    #[serde(default)]
+    build_cache: Option<Vec<ApiBuildCache>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiImage {
+    id: String,
+    #[serde(default)]
+    repo_tags: Option<Vec<String>>,
+    #[serde(default)]
+    created: i64,
+    #[serde(default)]
+    size: i64,
+    #[serde(default)]
+    shared_size: i64,
+    #[serde(default)]
+    containers: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiDfContainer {
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    size_rw: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiVolume {
+    name: String,
+    #[serde(default)]
+    driver: String,
+    #[serde(default)]
+    mountpoint: String,
+    #[serde(default)]
+    usage_data: Option<ApiVolumeUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiVolumeUsage {
+    #[serde(default)]
+    size: i64,
+    #[serde(default)]
+    ref_count: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiBuildCache {
+    #[serde(default)]
+    size: i64,
+    #[serde(default)]
+    in_use: bool,
+    #[serde(default)]
+    shared: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApiPruneReport {
+    #[serde(default)]
+    space_reclaimed: u64,
+}
+
+/// Last storage snapshot and when it was taken
+fn storage_cache() -> &'static Mutex<Option<(DockerStorage, Instant)>> {
+    static CACHE: OnceLock<Mutex<Option<(DockerStorage, Instant)>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(None))
+}
+
+/// Images, volumes and the `docker system df` summary, reusing a recent snapshot when possible
+pub async fn collect_storage(runtime: &Runtime) -> DockerStorage {
+    let Some(endpoint) = &runtime.endpoint else {
+        return DockerStorage {
+            error: Some(format!(
+                "{}: disk usage needs the engine API socket",
+                runtime.name()
+            )),
+            ..DockerStorage::default()
+        };
+    };
+
+    if let Some((cached, at)) = storage_cache()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        && at.elapsed() < STORAGE_TTL
+    {
+        return cached.clone();
+    }
+
+    let storage = match system_df(endpoint).await {
+        Ok(storage) => storage,
+        Err(e) => DockerStorage {
+            error: Some(format!("{}: {e}", runtime.name())),
+            ..DockerStorage::default()
+        },
+    };
+    *storage_cache().lock().unwrap_or_else(|e| e.into_inner()) =
+        Some((storage.clone(), Instant::now()));
+    storage
+}
+
+async fn system_df(endpoint: &Endpoint) -> Result<DockerStorage, String> {
+    let body = engine::request(endpoint, "GET", "/system/df", None).await?;
+    let df: ApiDiskUsage = serde_json::from_slice(&body).map_err(|e| e.to_string())?;
+
+    let mut images: Vec<DockerImage> = df
+        .images
+        .unwrap_or_default()
+        .into_iter()
+        .map(|img| {
+            let tags: Vec<String> = img
+                .repo_tags
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|t| t != "<none>:<none>")
+                .collect();
+            DockerImage {
+                id: img
+                    .id
+                    .trim_start_matches("sha256:")
+                    .chars()
+                    .take(12)
+                    .collect(),
+                dangling: tags.is_empty(),
+                tags,
+                size_bytes: img.size.max(0) as u64,
+                shared_bytes: img.shared_size.max(0) as u64,
+                containers: img.containers.max(0) as u32,
+                created: DateTime::<Utc>::from_timestamp(img.created, 0),
+            }
+        })
+        .collect();
+
+    let mut volumes: Vec<DockerVolume> = df
+        .volumes
+        .unwrap_or_default()
+        .into_iter()
+        .map(|vol| {
+            let usage = vol.usage_data.as_ref();
+            DockerVolume {
+                name: vol.name,
+                driver: vol.driver,
+                mountpoint: vol.mountpoint,
+                // -1 means the daemon did not compute it
+                size_bytes: usage.and_then(|u| (u.size >= 0).then_some(u.size as u64)),
+                ref_count: usage.map_or(0, |u| u.ref_count.max(0) as u32),
+            }
+        })
+        .collect();
+    images.sort_by_key(|i| std::cmp::Reverse(i.size_bytes));
+    volumes.sort_by_key(|v| std::cmp::Reverse(v.size_bytes));
+
+    let containers = df.containers.unwrap_or_default();
+    let build_cache = df.build_cache.unwrap_or_default();
+
+    let unused_images = images.iter().filter(|i| i.containers == 0);
+    let mut usage = vec![
+        DiskUsage {
+            kind: "images".to_string(),
+            total: images.len(),
+            active: images.iter().filter(|i| i.containers > 0).count(),
+            size_bytes: df.layers_size.max(0) as u64,
+            reclaimable_bytes: unused_images
+                .map(|i| i.size_bytes.saturating_sub(i.shared_bytes))
+                .sum(),
+        },
+        DiskUsage {
+            kind: "containers".to_string(),
+            total: containers.len(),
+            active: containers.iter().filter(|c| c.state == "running").count(),
+            size_bytes: containers.iter().map(|c| c.size_rw.max(0) as u64).sum(),
+            reclaimable_bytes: containers
+                .iter()
+                .filter(|c| c.state != "running")
+                .map(|c| c.size_rw.max(0) as u64)
+                .sum(),
+        },
+        DiskUsage {
+            kind: "volumes".to_string(),
+            total: volumes.len(),
+            active: volumes.iter().filter(|v| v.ref_count > 0).count(),
+            size_bytes: volumes.iter().filter_map(|v| v.size_bytes).sum(),
+            reclaimable_bytes: volumes
+                .iter()
+                .filter(|v| v.ref_count == 0)
+                .filter_map(|v| v.size_bytes)
+                .sum(),
+        },
+    ];
+    if !build_cache.is_empty() {
+        usage.push(DiskUsage {
+            kind: "build cache".to_string(),
+            total: build_cache.len(),
+            active: build_cache.iter().filter(|b| b.in_use).count(),
+            size_bytes: build_cache
+                .iter()
+                .filter(|b| !b.shared)
+                .map(|b| b.size.max(0) as u64)
+                .sum(),
+            reclaimable_bytes: build_cache
+                .iter()
+                .filter(|b| !b.in_use && !b.shared)
+                .map(|b| b.size.max(0) as u64)
+                .sum(),
+        });
+    }
+
+    Ok(DockerStorage {
+        images,
+        volumes,
+        usage,
+        error: None,
+    })
+}
+
+/// Prune unused objects of one kind (`images`, `containers`, `volumes`, `build cache`) and
+/// return the bytes reclaimed; image prune only removes dangling images, volume prune removes
+/// named volumes too
+pub async fn prune(runtime: &Runtime, kind: &str) -> Result<u64, String> {
+    let Some(endpoint) = &runtime.endpoint else {
+        return Err(format!(
+            "{}: prune needs the engine API socket",
+            runtime.name()
+        ));
+    };
+    let path = match kind {
+        "images" => "/images/prune",
+        "containers" => "/containers/prune",
+        // {"all":["true"]}: since API 1.42 a bare prune only removes anonymous volumes
+        "volumes" => "/volumes/prune?filters=%7B%22all%22%3A%5B%22true%22%5D%7D",
+        "build cache" => "/build/prune",
+        other => return Err(format!("cannot prune {other}")),
+    };
+    let body = match engine::request(endpoint, "POST", path, None).await {
+        // Older engines reject the `all` filter, but their bare prune already covers named volumes
+        Err(e) if kind == "volumes" && e.contains("invalid filter") => {
+            engine::request(endpoint, "POST", "/volumes/prune", None).await
+        }
+        result => result,
+    }
+    .map_err(|e| format!("{}: {e}", runtime.name()))?;
+
+    // Force the next refresh to re-read disk usage
+    *storage_cache().lock().unwrap_or_else(|e| e.into_inner()) = None;
+
+    Ok(serde_json::from_slice::<ApiPruneReport>(&body)
+        .map(|r| r.space_reclaimed)
+        .unwrap_or(0))
+}
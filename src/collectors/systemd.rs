@@ -0,0 +1,116 @@
+use serde::Deserialize;
+
+use crate::config::SystemdConfig;
+
+use super::gather::run_cmd;
+use super::types::{ServicesStatus, SystemdUnit};
+
+#[derive(Debug, Deserialize)]
+struct ListUnitsRow {
+    unit: String,
+    #[serde(default)]
+    load: String,
+    #[serde(default)]
+    active: String,
+    #[serde(default)]
+    sub: String,
+    #[serde(default)]
+    description: String,
+}
+
+pub async fn collect_systemd(cfg: &SystemdConfig) -> ServicesStatus {
+    // Once any unit is configured, an empty list means "skip that manager"
+    let configured = !cfg.units.is_empty() || !cfg.user_units.is_empty();
+    let (system, user) = tokio::join!(
+        async {
+            if configured && cfg.units.is_empty() {
+                Ok(vec![])
+            } else {
+                list_units(false, &cfg.units).await
+            }
+        },
+        async {
+            if configured && cfg.user_units.is_empty() {
+                Ok(vec![])
+            } else {
+                list_units(true, &cfg.user_units).await
+            }
+        }
+    );
+
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+    match system {
+        Ok(units) => items.extend(units),
+        Err(e) => errors.push(format!("system: {e}")),
+    }
+    match user {
+        Ok(units) => items.extend(units),
+        Err(e) => errors.push(format!("user: {e}")),
+    }
+
+    // Failed units first, then active, then the rest alphabetically
+    items.sort_by(|a, b| {
+        unit_rank(a)
+            .cmp(&unit_rank(b))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    ServicesStatus {
+        items,
+        error: if errors.is_empty() {
+            None
+        } else {
+            Some(format_systemd_error(&errors.join(" | ")))
+        },
+    }
+}
+
+async fn list_units(user: bool, patterns: &[String]) -> Result<Vec<SystemdUnit>, String> {
+    let mut args = Vec::new();
+    if user {
+        args.push("--user".to_string());
+    }
+    args.extend(["list-units", "--all", "--no-pager", "--output=json"].map(String::from));
+    if patterns.is_empty() {
+        args.push("--type=service".to_string());
+    } else {
+        args.extend(patterns.iter().cloned());
+    }
+
+    let raw = run_cmd("systemctl", args).await?;
+    let rows = serde_json::from_str::<Vec<ListUnitsRow>>(&raw)
+        .map_err(|e| format!("systemctl parse error: {e}"))?;
+
+    Ok(rows
+        .into_iter()
+        .filter(|r| r.load != "not-found" || !patterns.is_empty())
+        .map(|r| SystemdUnit {
+            name: r.unit,
+            description: r.description,
+            load: r.load,
+            active: r.active,
+            sub: r.sub,
+            user,
+        })
+        .collect())
+}
+
+fn unit_rank(u: &SystemdUnit) -> u8 {
+    match u.active.as_str() {
+        "failed" => 0,
+        "active" | "activating" | "reloading" => 1,
+        _ => 2,
+    }
+}
+
+fn format_systemd_error(err: &str) -> String {
+    let lower = err.to_lowercase();
+    if lower.contains("not been booted with systemd") {
+        return "systemd not running on this host".to_string();
+    }
+    if lower.contains("no such file") {
+        return "systemctl not found".to_string();
+    }
+    err.to_string()
+}
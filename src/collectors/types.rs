@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 
 use crate::plugin::PluginOutput;
@@ -9,6 +11,8 @@ pub struct DashboardData {
     pub docker: DockerStatus,
     pub aws: AwsStatus,
     pub prs: PrStatus,
+    pub ports: PortsStatus,
+    pub services: ServicesStatus,
     pub plugins: Vec<PluginOutput>,
     pub last_update: Option<DateTime<Utc>>,
 }
@@ -37,7 +41,7 @@ impl Default for GitStatus {
 #[derive(Debug, Clone)]
 pub struct SystemStatus {
     pub cpu_usage: f32,
-    pub cpu_cores: Vec<f32>,
+    pub cpu_cores: Vec<CpuCore>,
     pub mem_used_gb: f64,
     pub mem_total_gb: f64,
     pub mem_available_gb: f64,
@@ -51,6 +55,8 @@ pub struct SystemStatus {
     pub disk_used_gb: f64,
     pub network_rx_mb: f64,
     pub network_tx_mb: f64,
+    pub pressure: Option<PressureStats>,
+    pub cgroup: Option<CgroupStatus>,
 }
 
 impl Default for SystemStatus {
@@ -71,10 +77,56 @@ impl Default for SystemStatus {
             disk_used_gb: 0.0,
             network_rx_mb: 0.0,
             network_tx_mb: 0.0,
+            pressure: None,
+            cgroup: None,
         }
     }
 }
 
+/// Linux pressure-stall "some" averages for one resource (percent of wall time)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PsiAverages {
+    pub avg10: f32,
+    pub avg60: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PressureStats {
+    pub cpu: Option<PsiAverages>,
+    pub memory: Option<PsiAverages>,
+    pub io: Option<PsiAverages>,
+}
+
+/// Effective cgroup v2 limits and usage for the devdash process
+#[derive(Debug, Clone, Default)]
+pub struct CgroupStatus {
+    pub memory_max_bytes: Option<u64>,
+    pub memory_current_bytes: u64,
+    pub cpu_quota_cores: Option<f64>,
+    pub cpu_used_cores: Option<f64>,
+}
+
+impl CgroupStatus {
+    pub fn memory_pct(&self) -> Option<f64> {
+        self.memory_max_bytes
+            .filter(|max| *max > 0)
+            .map(|max| (self.memory_current_bytes as f64 / max as f64) * 100.0)
+    }
+
+    pub fn cpu_pct_of_quota(&self) -> Option<f64> {
+        match (self.cpu_used_cores, self.cpu_quota_cores) {
+            (Some(used), Some(quota)) if quota > 0.0 => Some((used / quota) * 100.0),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuCore {
+    pub usage: f32,
+    pub frequency_mhz: u64,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ProcessStat {
     pub pid: String,
@@ -87,19 +139,194 @@ pub struct ProcessStat {
     pub write_mb: f64,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct ListeningSocket {
+    pub protocol: String,
+    pub local_addr: String,
+    pub port: u16,
+    pub pid: Option<u32>,
+    pub process: String,
+    pub process_stat: Option<ProcessStat>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PortsStatus {
+    pub items: Vec<ListeningSocket>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SystemdUnit {
+    pub name: String,
+    pub description: String,
+    pub load: String,
+    pub active: String,
+    pub sub: String,
+    pub user: bool,
+}
+
+impl SystemdUnit {
+    pub fn is_failed(&self) -> bool {
+        self.active == "failed"
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ServicesStatus {
+    pub items: Vec<SystemdUnit>,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct DockerContainer {
     pub id: String,
     pub name: String,
+    /// Engine state: running, exited, restarting, paused, dead, created
+    pub state: String,
+    /// Human-readable status, e.g. `Up 3 hours (healthy)`
     pub status: String,
     pub image: String,
     pub ports: String,
+    pub created: Option<DateTime<Utc>>,
+    pub health: Option<String>,
+    pub exit_code: Option<i32>,
+    pub labels: BTreeMap<String, String>,
+    pub networks: Vec<String>,
+    pub mounts: Vec<String>,
+    pub stats: Option<ContainerStats>,
+}
+
+/// Resource usage sampled from the stats API; CPU needs two samples
+#[derive(Debug, Clone, Default)]
+pub struct ContainerStats {
+    pub cpu_pct: Option<f64>,
+    pub mem_used_bytes: u64,
+    pub mem_limit_bytes: u64,
+    pub net_rx_bytes: u64,
+    pub net_tx_bytes: u64,
+    pub blk_read_bytes: u64,
+    pub blk_write_bytes: u64,
+}
+
+impl ContainerStats {
+    pub fn mem_pct(&self) -> Option<f64> {
+        (self.mem_limit_bytes > 0)
+            .then(|| self.mem_used_bytes as f64 / self.mem_limit_bytes as f64 * 100.0)
+    }
+}
+
+impl DockerContainer {
+    pub fn compose_project(&self) -> Option<&str> {
+        self.label("com.docker.compose.project")
+    }
+
+    pub fn compose_service(&self) -> Option<&str> {
+        self.label("com.docker.compose.service")
+    }
+
+    pub fn label(&self, key: &str) -> Option<&str> {
+        self.labels
+            .get(key)
+            .map(String::as_str)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Patch state from an engine event until the next poll brings the full picture
+    pub fn apply_event(&mut self, event: &DockerEvent) {
+        match event.action.as_str() {
+            "start" | "restart" | "unpause" => {
+                self.state = "running".to_string();
+                self.status = "Up just now".to_string();
+                self.exit_code = None;
+            }
+            "pause" => {
+                self.state = "paused".to_string();
+                self.status = "Up (Paused)".to_string();
+            }
+            "die" => {
+                let code = event
+                    .detail
+                    .as_deref()
+                    .and_then(|d| d.strip_prefix("exit "))
+                    .and_then(|c| c.parse().ok());
+                self.state = "exited".to_string();
+                self.status = format!("Exited ({}) just now", code.unwrap_or(0));
+                self.exit_code = code;
+                self.health = None;
+                self.stats = None;
+            }
+            "health_status" => self.health = event.detail.clone(),
+            _ => {}
+        }
+    }
+
+    /// Unhealthy, crash-looping, dead or exited with a non-zero code
+    pub fn needs_attention(&self) -> bool {
+        self.health.as_deref() == Some("unhealthy")
+            || matches!(self.state.as_str(), "restarting" | "dead")
+            || (self.state == "exited" && self.exit_code.is_some_and(|c| c != 0))
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct DockerStatus {
-    pub running: Vec<String>,
+    /// Runtime name (`docker`, `podman`, `nerdctl`), empty when none was found
+    pub runtime: String,
+    pub source: String,
     pub items: Vec<DockerContainer>,
+    pub storage: DockerStorage,
+    pub error: Option<String>,
+}
+
+/// A container lifecycle event from the engine's event stream
+#[derive(Debug, Clone)]
+pub struct DockerEvent {
+    pub time: DateTime<Utc>,
+    /// Short container id, matching `DockerContainer::id`
+    pub id: String,
+    pub name: String,
+    /// `start`, `die`, `health_status`, ...
+    pub action: String,
+    /// Exit code, signal or new health state when the event carries one
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DockerImage {
+    pub id: String,
+    pub tags: Vec<String>,
+    pub dangling: bool,
+    pub size_bytes: u64,
+    pub shared_bytes: u64,
+    /// Containers (running or not) created from this image
+    pub containers: u32,
+    pub created: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DockerVolume {
+    pub name: String,
+    pub driver: String,
+    pub mountpoint: String,
+    pub size_bytes: Option<u64>,
+    pub ref_count: u32,
+}
+
+/// One row of the `docker system df` summary
+#[derive(Debug, Clone, Default)]
+pub struct DiskUsage {
+    pub kind: String,
+    pub total: usize,
+    pub active: usize,
+    pub size_bytes: u64,
+    pub reclaimable_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DockerStorage {
+    pub images: Vec<DockerImage>,
+    pub volumes: Vec<DockerVolume>,
+    pub usage: Vec<DiskUsage>,
     pub error: Option<String>,
 }
 
@@ -112,30 +339,332 @@ pub struct AwsInstance {
     pub az: String,
     pub public_ip: String,
     pub private_ip: String,
+    pub tags: BTreeMap<String, String>,
+    /// `sg-... (name)` per attached security group
+    pub security_groups: Vec<String>,
+    pub vpc_id: Option<String>,
+    pub subnet_id: Option<String>,
+    pub image_id: String,
+    pub launch_time: Option<DateTime<Utc>>,
+    pub key_name: Option<String>,
+    /// Instance profile name, from the end of its ARN
+    pub iam_profile: Option<String>,
+    /// `platformDetails`, e.g. `Linux/UNIX` or `Windows`
+    pub platform: String,
+    /// Credentials label and region of the target the instance was listed from
+    pub profile: String,
+    pub region: String,
+}
+
+impl AwsInstance {
+    /// `ssh` with the instance's key pair, to the public IP when it has one; none on Windows
+    pub fn ssh_command(&self) -> Option<String> {
+        if self.platform.contains("Windows") {
+            return None;
+        }
+        let host = [&self.public_ip, &self.private_ip]
+            .into_iter()
+            .find(|ip| !ip.is_empty() && *ip != "none")?;
+        let identity = self
+            .key_name
+            .as_ref()
+            .map_or(String::new(), |key| format!("-i ~/.ssh/{key}.pem "));
+        Some(format!("ssh {identity}ec2-user@{host}"))
+    }
+
+    /// Session Manager shell for the instance under the profile/region it was listed from
+    pub fn ssm_command(&self) -> String {
+        let mut cmd = format!(
+            "aws ssm start-session --target {} --region {}",
+            self.id, self.region
+        );
+        if let Some(profile) = self.profile.strip_prefix("profile ") {
+            cmd.push_str(&format!(" --profile {profile}"));
+        }
+        cmd
+    }
+}
+
+/// AWS resource kinds, each shown as its own view of the AWS pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AwsResource {
+    #[default]
+    Ec2,
+    Ecs,
+    Lambda,
+    Rds,
+    Alarms,
+}
+
+impl AwsResource {
+    pub const ALL: [AwsResource; 5] = [
+        AwsResource::Ec2,
+        AwsResource::Ecs,
+        AwsResource::Lambda,
+        AwsResource::Rds,
+        AwsResource::Alarms,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AwsResource::Ec2 => "EC2",
+            AwsResource::Ecs => "ECS",
+            AwsResource::Lambda => "LAMBDA",
+            AwsResource::Rds => "RDS",
+            AwsResource::Alarms => "ALARMS",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|r| *r == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+}
+
+/// Items listed per profile/region, so they can be grouped and cached by target
+pub trait AwsScoped {
+    fn profile(&self) -> &str;
+    fn region(&self) -> &str;
+}
+
+impl AwsScoped for AwsInstance {
+    fn profile(&self) -> &str {
+        &self.profile
+    }
+    fn region(&self) -> &str {
+        &self.region
+    }
+}
+
+impl AwsScoped for EcsService {
+    fn profile(&self) -> &str {
+        &self.profile
+    }
+    fn region(&self) -> &str {
+        &self.region
+    }
+}
+
+impl AwsScoped for LambdaFunction {
+    fn profile(&self) -> &str {
+        &self.profile
+    }
+    fn region(&self) -> &str {
+        &self.region
+    }
+}
+
+impl AwsScoped for RdsInstance {
+    fn profile(&self) -> &str {
+        &self.profile
+    }
+    fn region(&self) -> &str {
+        &self.region
+    }
+}
+
+impl AwsScoped for CloudWatchAlarm {
+    fn profile(&self) -> &str {
+        &self.profile
+    }
+    fn region(&self) -> &str {
+        &self.region
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct EcsService {
+    pub cluster: String,
+    pub name: String,
+    pub status: String,
+    pub desired: u64,
+    pub running: u64,
+    pub pending: u64,
+    pub launch_type: String,
+    pub profile: String,
+    pub region: String,
+}
+
+impl EcsService {
+    /// `steady` when every desired task runs, `degraded` otherwise
+    pub fn health(&self) -> &'static str {
+        if self.running >= self.desired {
+            "steady"
+        } else {
+            "degraded"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LambdaFunction {
+    pub name: String,
+    pub runtime: String,
+    /// As reported by Lambda, e.g. `2024-05-01T10:00:00.000+0000`
+    pub last_modified: String,
+    pub memory_mb: u64,
+    pub timeout_secs: u64,
+    pub profile: String,
+    pub region: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RdsInstance {
+    pub id: String,
+    pub status: String,
+    pub engine: String,
+    pub engine_version: String,
+    pub class: String,
+    pub endpoint: String,
+    pub multi_az: bool,
+    pub profile: String,
+    pub region: String,
+}
+
+/// A CloudWatch metric alarm currently in `ALARM`
+#[derive(Debug, Clone, Default)]
+pub struct CloudWatchAlarm {
+    pub name: String,
+    pub reason: String,
+    pub since: String,
+    pub namespace: String,
+    pub metric: String,
+    pub profile: String,
+    pub region: String,
+}
+
+/// Outcome of listing one profile/region pair
+#[derive(Debug, Clone, Default)]
+pub struct AwsTarget {
+    /// `env` or `profile <name>`
+    pub profile: String,
+    /// From STS `GetCallerIdentity`; `None` when it could not be looked up
+    pub account: Option<String>,
+    pub region: String,
+    /// Failures per resource kind; credential problems show up under every kind
+    pub errors: Vec<(AwsResource, String)>,
+}
+
+impl AwsTarget {
+    pub fn contains(&self, item: &impl AwsScoped) -> bool {
+        item.profile() == self.profile && item.region() == self.region
+    }
+
+    pub fn error(&self, kind: AwsResource) -> Option<&str> {
+        self.errors
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, e)| e.as_str())
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct AwsStatus {
-    pub instances: Vec<String>,
     pub items: Vec<AwsInstance>,
+    pub ecs: Vec<EcsService>,
+    pub lambdas: Vec<LambdaFunction>,
+    pub databases: Vec<RdsInstance>,
+    pub alarms: Vec<CloudWatchAlarm>,
+    pub targets: Vec<AwsTarget>,
     pub source: String,
+    /// Set when EC2 could not be listed anywhere; per-target failures live on `targets`
     pub error: Option<String>,
 }
 
+impl AwsStatus {
+    /// Only `target`'s items of `kind`, as kept by the fallback cache
+    pub fn subset(&self, target: &AwsTarget, kind: AwsResource) -> AwsStatus {
+        fn pick<T: AwsScoped + Clone>(items: &[T], target: &AwsTarget) -> Vec<T> {
+            items
+                .iter()
+                .filter(|i| target.contains(*i))
+                .cloned()
+                .collect()
+        }
+        let mut out = AwsStatus::default();
+        match kind {
+            AwsResource::Ec2 => out.items = pick(&self.items, target),
+            AwsResource::Ecs => out.ecs = pick(&self.ecs, target),
+            AwsResource::Lambda => out.lambdas = pick(&self.lambdas, target),
+            AwsResource::Rds => out.databases = pick(&self.databases, target),
+            AwsResource::Alarms => out.alarms = pick(&self.alarms, target),
+        }
+        out
+    }
+
+    /// Mark every item as listed from `profile` in `region`
+    pub fn set_scope(&mut self, profile: &str, region: &str) {
+        let scope = || (profile.to_string(), region.to_string());
+        for i in &mut self.items {
+            (i.profile, i.region) = scope();
+        }
+        for s in &mut self.ecs {
+            (s.profile, s.region) = scope();
+        }
+        for f in &mut self.lambdas {
+            (f.profile, f.region) = scope();
+        }
+        for d in &mut self.databases {
+            (d.profile, d.region) = scope();
+        }
+        for a in &mut self.alarms {
+            (a.profile, a.region) = scope();
+        }
+    }
+
+    pub fn extend(&mut self, other: AwsStatus) {
+        self.items.extend(other.items);
+        self.ecs.extend(other.ecs);
+        self.lambdas.extend(other.lambdas);
+        self.databases.extend(other.databases);
+        self.alarms.extend(other.alarms);
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PrItem {
     pub number: u64,
     pub title: String,
     pub author: String,
     pub url: String,
+    /// `owner/name` the PR belongs to
+    pub repo: String,
+    pub created_at: String,
     pub updated_at: String,
     pub body: Option<String>,
+    pub draft: bool,
+    /// `approved`, `changes requested` or `review required`; none when reviews aren't required
+    pub review_decision: Option<String>,
+    /// Users (logins) and teams (names) still asked to review
+    pub requested_reviewers: Vec<String>,
+    /// Head commit check rollup: `success`, `failure` or `pending`; none without checks
+    pub checks: Option<String>,
+    pub labels: Vec<String>,
+    /// `mergeable`, `conflicting` or `unknown` while GitHub computes it
+    pub mergeable: String,
+    pub additions: u64,
+    pub deletions: u64,
 }
 
+/// One PR filter's newest matches; `total` also counts those beyond the fetched rows
 #[derive(Debug, Clone, Default)]
-pub struct PrStatus {
-    pub open: Vec<String>,
+pub struct PrView {
+    pub name: String,
+    pub total: u64,
     pub items: Vec<PrItem>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PrStatus {
+    /// `all` first, then the configured filters in order
+    pub views: Vec<PrView>,
     pub source: String,
     pub error: Option<String>,
 }
+
+impl PrStatus {
+    /// Rows of the view at `idx`; empty when it wasn't fetched
+    pub fn items(&self, idx: usize) -> &[PrItem] {
+        self.views.get(idx).map_or(&[], |v| v.items.as_slice())
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -14,6 +15,9 @@ pub struct Config {
     pub system_ui: SystemUiConfig,
     pub aws: AwsConfig,
     pub github: GitHubConfig,
+    pub docker: DockerConfig,
+    pub systemd: SystemdConfig,
+    pub logs: LogsConfig,
     pub plugins: Vec<PluginConfig>,
 }
 
@@ -22,7 +26,17 @@ pub struct Config {
 #[serde(default)]
 pub struct AwsConfig {
     pub region: Option<String>,
+    /// Regions to list side by side; replaces `region` when non-empty
+    pub regions: Vec<String>,
     pub profile: Option<String>,
+    /// Profiles (usually one per account) to list side by side; replaces `profile` when non-empty
+    pub profiles: Vec<String>,
+    /// Override for every service endpoint, e.g. `http://localhost:4566` for LocalStack
+    pub endpoint_url: Option<String>,
+    /// Server-side tag filters, e.g. `Owner = "me"`; instances must match all of them
+    pub tags: BTreeMap<String, String>,
+    /// Only list instances in these states (`running`, `stopped`, ...); empty lists all
+    pub states: Vec<String>,
 }
 
 /// GitHub integration configuration
@@ -30,7 +44,71 @@ pub struct AwsConfig {
 #[serde(default)]
 pub struct GitHubConfig {
     pub repo: Option<String>,
+    /// Repositories (`owner/repo`) merged into one list; replaces `repo` when non-empty
+    pub repos: Vec<String>,
+    /// Extra search scope such as `org:acme` or `user:me`, listed alongside the repos
+    pub query: Option<String>,
+    /// REST API base, e.g. `https://github.example.com/api/v3` for GitHub Enterprise Server
+    pub api_url: String,
     pub token_env: String,
+    /// Saved PR filters cycled with `f` on the PR pane, after the built-in `all`
+    pub filters: Vec<PrFilterConfig>,
+}
+
+/// A named PR search; every set condition must hold
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct PrFilterConfig {
+    pub name: String,
+    /// Authored by the authenticated user
+    pub mine: bool,
+    /// The authenticated user (or one of their teams) is asked to review
+    pub review_requested: bool,
+    pub assigned: bool,
+    /// Carries all of these labels
+    pub labels: Vec<String>,
+    pub exclude_drafts: bool,
+}
+
+/// Container runtime and Docker Engine API connection settings
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DockerConfig {
+    /// `auto`, `docker`, `podman`, `nerdctl`, or a Docker-compatible socket path / URL
+    pub runtime: String,
+    /// Engine endpoint (`unix:///path` or `tcp://host:port`); falls back to `DOCKER_HOST`
+    pub host: Option<String>,
+    /// Command run by `exec -it` when opening a shell in a container, e.g. `bash -l`
+    pub shell: String,
+}
+
+/// systemd units shown in the Services pane; leave both empty to show every service unit
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct SystemdConfig {
+    pub units: Vec<String>,
+    pub user_units: Vec<String>,
+}
+
+/// Log pane sources and severity highlighting rules
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LogsConfig {
+    pub buffer_lines: usize,
+    pub error_pattern: String,
+    pub warn_pattern: String,
+    pub debug_pattern: String,
+    pub sources: Vec<LogSourceConfig>,
+}
+
+/// A tailed file or a `journalctl -f` unit
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct LogSourceConfig {
+    pub name: String,
+    pub file: Option<PathBuf>,
+    pub journal_unit: Option<String>,
+    pub user: bool,
 }
 
 /// System monitoring alert thresholds
@@ -72,16 +150,61 @@ impl Default for Config {
             system_ui: SystemUiConfig::default(),
             aws: AwsConfig::default(),
             github: GitHubConfig::default(),
+            docker: DockerConfig::default(),
+            systemd: SystemdConfig::default(),
+            logs: LogsConfig::default(),
             plugins: vec![],
         }
     }
 }
 
+impl Default for DockerConfig {
+    fn default() -> Self {
+        Self {
+            runtime: "auto".to_string(),
+            host: None,
+            shell: "sh".to_string(),
+        }
+    }
+}
+
 impl Default for GitHubConfig {
     fn default() -> Self {
         Self {
             repo: None,
+            repos: Vec::new(),
+            query: None,
+            api_url: "https://api.github.com".to_string(),
             token_env: "GITHUB_TOKEN".to_string(),
+            filters: vec![
+                PrFilterConfig {
+                    name: "mine".to_string(),
+                    mine: true,
+                    ..PrFilterConfig::default()
+                },
+                PrFilterConfig {
+                    name: "review".to_string(),
+                    review_requested: true,
+                    ..PrFilterConfig::default()
+                },
+                PrFilterConfig {
+                    name: "assigned".to_string(),
+                    assigned: true,
+                    ..PrFilterConfig::default()
+                },
+            ],
+        }
+    }
+}
+
+impl Default for LogsConfig {
+    fn default() -> Self {
+        Self {
+            buffer_lines: 2000,
+            error_pattern: r"(?i)\b(error|err|fatal|panic|crit(ical)?|emerg|alert)\b".to_string(),
+            warn_pattern: r"(?i)\bwarn(ing)?\b".to_string(),
+            debug_pattern: r"(?i)\b(debug|trace)\b".to_string(),
+            sources: vec![],
         }
     }
 }
@@ -170,6 +293,83 @@ impl Config {
                 self.alerts.stale_crit_secs
             );
         }
+        for (key, pattern) in [
+            ("error_pattern", &self.logs.error_pattern),
+            ("warn_pattern", &self.logs.warn_pattern),
+            ("debug_pattern", &self.logs.debug_pattern),
+        ] {
+            if let Err(e) = regex::Regex::new(pattern) {
+                anyhow::bail!("logs.{key} is not a valid regex: {e}");
+            }
+        }
+        let runtime = self.docker.runtime.trim();
+        if !matches!(runtime, "" | "auto" | "docker" | "podman" | "nerdctl")
+            && !runtime.starts_with('/')
+            && !runtime.contains("://")
+        {
+            anyhow::bail!(
+                "docker.runtime must be auto, docker, podman, nerdctl or a socket path (got '{runtime}')"
+            );
+        }
+        if let Some(url) = &self.aws.endpoint_url
+            && !url.starts_with("http://")
+            && !url.starts_with("https://")
+        {
+            anyhow::bail!("aws.endpoint_url must start with http:// or https:// (got '{url}')");
+        }
+        if self
+            .aws
+            .regions
+            .iter()
+            .chain(&self.aws.profiles)
+            .any(|v| v.trim().is_empty())
+        {
+            anyhow::bail!("aws.regions and aws.profiles must not contain empty entries");
+        }
+        for state in &self.aws.states {
+            if !matches!(
+                state.as_str(),
+                "pending" | "running" | "shutting-down" | "terminated" | "stopping" | "stopped"
+            ) {
+                anyhow::bail!(
+                    "aws.states entries must be pending, running, shutting-down, terminated, stopping or stopped (got '{state}')"
+                );
+            }
+        }
+        if let Some(repo) = self
+            .github
+            .repos
+            .iter()
+            .chain(&self.github.repo)
+            .find(|r| r.split('/').filter(|part| !part.trim().is_empty()).count() != 2)
+        {
+            anyhow::bail!("github repos must look like owner/repo (got '{repo}')");
+        }
+        let api_url = &self.github.api_url;
+        if !api_url.starts_with("http://") && !api_url.starts_with("https://") {
+            anyhow::bail!("github.api_url must start with http:// or https:// (got '{api_url}')");
+        }
+        let mut filter_names = vec!["all"];
+        for filter in &self.github.filters {
+            let name = filter.name.trim();
+            if name.is_empty() || filter_names.contains(&name) {
+                anyhow::bail!(
+                    "github.filters need unique, non-empty names other than 'all' (got '{name}')"
+                );
+            }
+            filter_names.push(name);
+        }
+        if self.docker.shell.trim().is_empty() {
+            anyhow::bail!("docker.shell must not be empty");
+        }
+        for source in &self.logs.sources {
+            if source.file.is_none() && source.journal_unit.is_none() {
+                anyhow::bail!(
+                    "log source '{}' needs either `file` or `journal_unit`",
+                    source.name
+                );
+            }
+        }
         Ok(())
     }
 }
@@ -1,7 +1,9 @@
+mod actions;
 mod app;
 mod collectors;
 mod config;
 mod plugin;
+mod streams;
 mod ui;
 
 use anyhow::{Context, Result};
@@ -18,10 +20,12 @@ use ratatui::{Terminal, backend::CrosstermBackend, layout::Rect};
 use std::io;
 use tokio::sync::mpsc;
 
-use app::{App, NavDir, PaletteCommand, Pane};
-use collectors::{DataCache, apply_cache, collect_all};
+use actions::{Action, ActionOutcome, ContainerVerb, Ec2Verb, UnitVerb};
+use app::{App, NavDir, PaletteCommand, Pane, StreamRequest, TerminalCommand};
+use collectors::{DataCache, DockerEvent, apply_cache, collect_all};
 use config::Config;
 use plugin::PluginManager;
+use streams::StreamLine;
 
 /// Messages for controlling data collection
 enum ControlMsg {
@@ -47,16 +51,56 @@ async fn main() -> Result<()> {
 
     let run_result = run_app(&mut terminal, cfg, plugins).await;
 
-    disable_raw_mode().ok();
+    suspend_tui(&mut terminal).ok();
+
+    run_result
+}
+
+/// Give the terminal back to the shell: cooked mode, main screen, no mouse capture
+fn suspend_tui(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+    disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
         DisableMouseCapture
-    )
-    .ok();
-    terminal.show_cursor().ok();
+    )?;
+    terminal.show_cursor()?;
+    Ok(())
+}
 
-    run_result
+/// Take the terminal back after `suspend_tui` and force a full redraw
+fn resume_tui(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+    enable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    terminal.clear()?;
+    Ok(())
+}
+
+/// Run an interactive command in the foreground with the TUI suspended around it.
+/// The caller must not be reading terminal events while this runs.
+async fn run_in_terminal(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    command: &TerminalCommand,
+) -> Result<std::process::ExitStatus> {
+    // Ctrl-C in cooked mode reaches the whole process group; let only the child react to it
+    let _sigint = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::interrupt())?;
+    suspend_tui(terminal)?;
+    println!(
+        "devdash: {} {} (exit to return to the dashboard)",
+        command.program,
+        command.args.join(" ")
+    );
+    let status = tokio::process::Command::new(&command.program)
+        .args(&command.args)
+        .status()
+        .await
+        .with_context(|| format!("failed to start {}", command.program));
+    resume_tui(terminal)?;
+    status
 }
 
 /// Main application loop handling UI rendering and event processing
@@ -72,6 +116,11 @@ async fn run_app(
     let (data_tx, mut data_rx) = mpsc::channel(8);
     let (status_tx, mut status_rx) = mpsc::channel(8);
     let (ctrl_tx, mut ctrl_rx) = mpsc::channel(8);
+    let (action_tx, mut action_rx) = mpsc::channel::<ActionOutcome>(8);
+    let (log_tx, mut log_rx) = mpsc::channel::<StreamLine>(256);
+    let (viewer_tx, mut viewer_rx) = mpsc::channel::<StreamLine>(256);
+    let (event_tx, mut event_rx) = mpsc::channel::<DockerEvent>(64);
+    let mut viewer_task: Option<tokio::task::JoinHandle<()>> = None;
 
     // Background task for periodic data collection
     let mut collector_cfg = cfg.clone();
@@ -81,6 +130,9 @@ async fn run_app(
             collector_cfg.refresh_seconds.max(1),
         ));
         let mut cache = DataCache::default();
+        let mut log_tasks = streams::spawn_log_tailers(&collector_cfg.logs, log_tx.clone());
+        let mut events_task =
+            streams::spawn_docker_events(collector_cfg.docker.clone(), event_tx.clone());
 
         loop {
             tokio::select! {
@@ -91,6 +143,16 @@ async fn run_app(
                         Some(ControlMsg::ReloadRuntime { cfg, plugins }) => {
                             collector_cfg = *cfg;
                             plugin_mgr = *plugins;
+                            for task in log_tasks.drain(..) {
+                                task.abort();
+                            }
+                            log_tasks =
+                                streams::spawn_log_tailers(&collector_cfg.logs, log_tx.clone());
+                            events_task.abort();
+                            events_task = streams::spawn_docker_events(
+                                collector_cfg.docker.clone(),
+                                event_tx.clone(),
+                            );
                             interval = tokio::time::interval(std::time::Duration::from_secs(
                                 collector_cfg.refresh_seconds.max(1),
                             ));
@@ -101,7 +163,8 @@ async fn run_app(
             }
 
             let _ = status_tx.send(true).await;
-            let mut data = collect_all(&collector_cfg, &plugin_mgr).await;
+            let aws_reuse = cache.aws_reusable();
+            let mut data = collect_all(&collector_cfg, &plugin_mgr, &aws_reuse).await;
             apply_cache(&mut data, &mut cache, collector_cfg.cache_seconds.max(1));
             let _ = status_tx.send(false).await;
 
@@ -119,11 +182,52 @@ async fn run_app(
     let mut spinner_tick = tokio::time::interval(std::time::Duration::from_millis(120));
 
     loop {
+        if let Some(request) = app.pending_stream.take() {
+            if let Some(task) = viewer_task.take() {
+                task.abort();
+            }
+            if let StreamRequest::ContainerLogs {
+                docker,
+                id,
+                tail,
+                since_secs,
+                tag,
+            } = request
+            {
+                viewer_task = Some(streams::spawn_container_logs(
+                    docker,
+                    id,
+                    tail,
+                    since_secs,
+                    tag,
+                    viewer_tx.clone(),
+                ));
+            }
+        }
+
+        if let Some(command) = app.pending_terminal.take() {
+            // The event reader would compete with the child for stdin
+            drop(reader);
+            let result = run_in_terminal(terminal, &command).await;
+            reader = EventStream::new();
+            match result {
+                Ok(status) if status.success() => app.set_status(format!("left {}", command.label)),
+                Ok(status) => app.set_error(format!("{} exited with {status}", command.label)),
+                Err(e) => app.set_error(format!("{}: {e:#}", command.label)),
+            }
+            if ctrl_tx.send(ControlMsg::RefreshNow).await.is_ok() {
+                app.loading = true;
+            }
+        }
+
         terminal.draw(|frame| ui::render(frame, &app))?;
 
         tokio::select! {
             _ = spinner_tick.tick() => {
                 app.tick_spinner();
+                if app.take_due_refresh() && ctrl_tx.send(ControlMsg::RefreshNow).await.is_ok() {
+                    app.loading = true;
+                }
             }
             maybe_loading = status_rx.recv() => {
                 if let Some(loading) = maybe_loading {
@@ -135,11 +239,40 @@ async fn run_app(
                     app.update_data(data);
                 }
             }
+            maybe_line = log_rx.recv() => {
+                if let Some(line) = maybe_line {
+                    app.push_log_line(line);
+                }
+            }
+            maybe_event = event_rx.recv() => {
+                if let Some(event) = maybe_event {
+                    app.push_docker_event(event);
+                }
+            }
+            maybe_line = viewer_rx.recv() => {
+                if let Some(line) = maybe_line {
+                    app.push_viewer_line(line);
+                }
+            }
+            maybe_outcome = action_rx.recv() => {
+                if let Some(outcome) = maybe_outcome {
+                    match outcome.result {
+                        Ok(msg) => app.set_status(msg),
+                        Err(e) => app.set_error(format!("{} failed: {e}", outcome.label)),
+                    }
+                    if ctrl_tx.send(ControlMsg::RefreshNow).await.is_ok() {
+                        app.loading = true;
+                    }
+                }
+            }
             maybe_event = reader.next() => {
                 if let Some(Ok(event)) = maybe_event {
                     match event {
+                        // Keeps the key press and the quit check apart; newer clippy would fold
+                        // the `await` into the guard
+                        #[allow(clippy::collapsible_match)]
                         Event::Key(key) if key.kind == KeyEventKind::Press => {
-                            if handle_key(key.code, key.modifiers, &mut app, &ctrl_tx).await {
+                            if handle_key(key.code, key.modifiers, &mut app, &ctrl_tx, &action_tx).await {
                                 break;
                             }
                         }
@@ -171,11 +304,76 @@ async fn handle_key(
     modifiers: KeyModifiers,
     app: &mut App,
     ctrl_tx: &mpsc::Sender<ControlMsg>,
+    action_tx: &mpsc::Sender<ActionOutcome>,
 ) -> bool {
+    // Handle confirmation prompt keys
+    if let Some(confirm) = &app.confirm {
+        let action = confirm.action.clone();
+        if let Some(phrase) = action.confirm_phrase() {
+            match code {
+                KeyCode::Enter if app.confirm_input == phrase => {
+                    app.cancel_confirm();
+                    app.begin_action(&action);
+                    spawn_action(action, action_tx.clone());
+                }
+                KeyCode::Enter => app.set_status(format!("type {phrase} exactly to confirm")),
+                KeyCode::Esc => app.cancel_confirm(),
+                KeyCode::Backspace => {
+                    app.confirm_input.pop();
+                }
+                KeyCode::Char(c) => app.confirm_input.push(c),
+                _ => {}
+            }
+            return false;
+        }
+        match code {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                app.cancel_confirm();
+                app.begin_action(&action);
+                spawn_action(action, action_tx.clone());
+            }
+            KeyCode::Char('n') | KeyCode::Esc | KeyCode::Char('q') => app.cancel_confirm(),
+            _ => {}
+        }
+        return false;
+    }
+
+    // Handle the full-screen container log viewer
+    if app.log_viewer.is_some() {
+        handle_viewer_key(code, app);
+        return false;
+    }
+
     // Handle modal dialog keys
     if app.detail_modal.is_some() {
         match code {
             KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.close_details(),
+            KeyCode::Char('p') if app.selected == Pane::Ports => {
+                app.close_details();
+                app.jump_to_port_process();
+            }
+            KeyCode::Char(c @ ('s' | 'x' | 'R')) if app.selected == Pane::Services => {
+                app.close_details();
+                app.request_unit_action(match c {
+                    's' => UnitVerb::Start,
+                    'x' => UnitVerb::Stop,
+                    _ => UnitVerb::Restart,
+                });
+            }
+            KeyCode::Char('l') if app.selected == Pane::Docker => app.open_container_logs(),
+            KeyCode::Char('e') if app.selected == Pane::Docker => app.exec_into_container(),
+            KeyCode::Char(c) if app.selected == Pane::Aws => {
+                if let Some(verb) = ec2_verb_for(c) {
+                    app.close_details();
+                    app.request_instance_action(verb);
+                }
+            }
+            KeyCode::Char(c) if app.selected == Pane::Docker => {
+                if let Some(verb) = container_verb_for(c) {
+                    app.close_details();
+                    app.request_container_action(verb);
+                }
+            }
             _ => {}
         }
         return false;
@@ -186,13 +384,28 @@ async fn handle_key(
         return handle_command_mode(code, app, ctrl_tx).await;
     }
 
+    // Handle list filter input
+    if app.filter_mode {
+        handle_filter_mode(code, app);
+        return false;
+    }
+
+    if app.selected == Pane::Logs
+        && !modifiers.contains(KeyModifiers::CONTROL)
+        && handle_logs_key(code, app)
+    {
+        return false;
+    }
+
     // Handle normal navigation and control keys
     match code {
         KeyCode::Char('q') | KeyCode::Esc | KeyCode::F(10) => return true,
         KeyCode::Char(':') => app.enter_command_mode(),
+        KeyCode::Char('/') => app.enter_filter_mode(),
         KeyCode::Tab => app.select_next(),
         KeyCode::BackTab => app.select_prev(),
         KeyCode::Char('h') => app.select_directional(NavDir::Left),
+        KeyCode::Char('l') if app.selected == Pane::Docker => app.open_container_logs(),
         KeyCode::Char('l') => app.select_directional(NavDir::Right),
         KeyCode::Char('j') => {
             if app.can_scroll_list() {
@@ -241,6 +454,36 @@ async fn handle_key(
             }
         }
         KeyCode::Enter => app.open_details_for_selected(),
+        KeyCode::Char('p') if app.selected == Pane::Ports => app.jump_to_port_process(),
+        KeyCode::Char('s') if app.selected == Pane::Services => {
+            app.request_unit_action(UnitVerb::Start)
+        }
+        KeyCode::Char('x') if app.selected == Pane::Services => {
+            app.request_unit_action(UnitVerb::Stop)
+        }
+        KeyCode::Char('R') if app.selected == Pane::Services => {
+            app.request_unit_action(UnitVerb::Restart)
+        }
+        KeyCode::Char('f') if app.selected == Pane::Docker => app.cycle_docker_filter(),
+        KeyCode::Char('f') if app.selected == Pane::Prs => app.cycle_pr_view(),
+        KeyCode::Char('o') if app.selected == Pane::Prs => app.cycle_pr_sort(),
+        KeyCode::Char('o') if app.selected == Pane::Docker => app.cycle_docker_sort(),
+        KeyCode::Char('i') if app.selected == Pane::Docker => app.toggle_docker_storage_view(),
+        KeyCode::Char('e') if app.selected == Pane::Docker => app.exec_into_container(),
+        KeyCode::Char(c) if app.selected == Pane::Docker && container_verb_for(c).is_some() => {
+            if let Some(verb) = container_verb_for(c) {
+                app.request_container_action(verb);
+            }
+        }
+        KeyCode::Char('v') if app.selected == Pane::Aws => app.cycle_aws_view(),
+        KeyCode::Char(c) if app.selected == Pane::Aws && ec2_verb_for(c).is_some() => {
+            if let Some(verb) = ec2_verb_for(c) {
+                app.request_instance_action(verb);
+            }
+        }
+        KeyCode::Char('c') if app.selected == Pane::System => {
+            app.system_core_view = !app.system_core_view;
+        }
         KeyCode::Char('+') | KeyCode::Char('=') => app.resize_focused(1),
         KeyCode::Char('-') => app.resize_focused(-1),
         KeyCode::Char('r') | KeyCode::F(5) => {
@@ -256,12 +499,122 @@ async fn handle_key(
         KeyCode::Char('4') => app.selected = Pane::Docker,
         KeyCode::Char('5') => app.selected = Pane::Aws,
         KeyCode::Char('6') => app.selected = Pane::Plugins,
+        KeyCode::Char('7') => app.selected = Pane::Ports,
+        KeyCode::Char('8') => app.selected = Pane::Services,
+        KeyCode::Char('9') => app.selected = Pane::Logs,
         _ => {}
     }
 
     false
 }
 
+/// Scrolling, pause and search keys for the Logs pane, returns true if handled
+fn handle_logs_key(code: KeyCode, app: &mut App) -> bool {
+    match code {
+        KeyCode::Char('j') | KeyCode::Down => app.scroll_logs(-1),
+        KeyCode::Char('k') | KeyCode::Up => app.scroll_logs(1),
+        KeyCode::PageDown => app.scroll_logs(-10),
+        KeyCode::PageUp => app.scroll_logs(10),
+        KeyCode::Char(' ') => app.logs.toggle_pause(),
+        KeyCode::Char('G') | KeyCode::End => app.logs.follow(),
+        KeyCode::Char('s') => app.cycle_log_source(),
+        KeyCode::Char('n') => app.search_logs(true),
+        KeyCode::Char('N') => app.search_logs(false),
+        KeyCode::Char('C') => {
+            app.logs.clear();
+            app.set_status("log buffer cleared");
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Keys for the container log viewer: scrolling, follow/pause, search and history window
+fn handle_viewer_key(code: KeyCode, app: &mut App) {
+    let Some(viewer) = &mut app.log_viewer else {
+        return;
+    };
+
+    if viewer.search_mode {
+        match code {
+            KeyCode::Esc => {
+                viewer.search.clear();
+                viewer.search_mode = false;
+            }
+            KeyCode::Enter => viewer.search_mode = false,
+            KeyCode::Backspace => {
+                viewer.search.pop();
+            }
+            KeyCode::Char(c) => {
+                viewer.search.push(c);
+                viewer.buffer.follow();
+                app.search_viewer(true);
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => app.close_log_viewer(),
+        KeyCode::Char('j') | KeyCode::Down => app.scroll_viewer(-1),
+        KeyCode::Char('k') | KeyCode::Up => app.scroll_viewer(1),
+        KeyCode::PageDown => app.scroll_viewer(-20),
+        KeyCode::PageUp => app.scroll_viewer(20),
+        KeyCode::Char(' ') => viewer.buffer.toggle_pause(),
+        KeyCode::Char('G') | KeyCode::End => viewer.buffer.follow(),
+        KeyCode::Char('/') => viewer.search_mode = true,
+        KeyCode::Char('n') => app.search_viewer(true),
+        KeyCode::Char('N') => app.search_viewer(false),
+        KeyCode::Char('t') => app.cycle_log_window(),
+        _ => {}
+    }
+}
+
+/// Docker pane lifecycle keys
+fn container_verb_for(c: char) -> Option<ContainerVerb> {
+    match c {
+        's' => Some(ContainerVerb::Start),
+        'x' => Some(ContainerVerb::Stop),
+        'R' => Some(ContainerVerb::Restart),
+        'P' => Some(ContainerVerb::Pause),
+        'D' => Some(ContainerVerb::Remove),
+        _ => None,
+    }
+}
+
+/// AWS pane instance keys, mirroring the Docker ones
+fn ec2_verb_for(c: char) -> Option<Ec2Verb> {
+    match c {
+        's' => Some(Ec2Verb::Start),
+        'x' => Some(Ec2Verb::Stop),
+        'R' => Some(Ec2Verb::Reboot),
+        'D' => Some(Ec2Verb::Terminate),
+        _ => None,
+    }
+}
+
+/// Run a confirmed action in the background and report its outcome to the UI loop
+fn spawn_action(action: Action, tx: mpsc::Sender<ActionOutcome>) {
+    tokio::spawn(async move {
+        let _ = tx.send(action.run().await).await;
+    });
+}
+
+/// Handle `/` filter input for the focused list pane
+fn handle_filter_mode(code: KeyCode, app: &mut App) {
+    match code {
+        KeyCode::Esc => {
+            app.clear_filter();
+            app.exit_filter_mode();
+        }
+        KeyCode::Enter => app.exit_filter_mode(),
+        KeyCode::Backspace => app.pop_filter_char(),
+        KeyCode::Char(c) => app.push_filter_char(c),
+        _ => {}
+    }
+}
+
 /// Handle command palette input, returns true if app should quit
 async fn handle_command_mode(
     code: KeyCode,
@@ -337,13 +690,22 @@ async fn execute_palette_command(
                 "compact mode off"
             });
         }
+        PaletteCommand::ToggleCoreView => {
+            app.system_core_view = !app.system_core_view;
+            app.selected = Pane::System;
+            app.set_status(if app.system_core_view {
+                "core heatmap on"
+            } else {
+                "core heatmap off"
+            });
+        }
         PaletteCommand::Focus(pane) => {
             app.selected = pane;
             app.set_status("focus changed");
         }
         PaletteCommand::Quit => return true,
         PaletteCommand::Help => {
-            app.set_status("commands: refresh | reload | compact | focus <pane> | quit");
+            app.set_status("commands: refresh | reload | compact | cores | focus <pane> | quit");
         }
     }
 
@@ -0,0 +1,307 @@
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::collectors::{DockerEvent, Runtime, follow_container_logs, follow_docker_events};
+use crate::config::{DockerConfig, LogSourceConfig, LogsConfig};
+
+/// One line of output from a long-running source, tagged with its origin
+#[derive(Debug, Clone)]
+pub struct StreamLine {
+    pub source: String,
+    pub text: String,
+}
+
+/// Bytes read back from the end of a file when tailing starts
+const FILE_BACKLOG_BYTES: u64 = 16 * 1024;
+
+/// Spawn one tailer per configured log source
+pub fn spawn_log_tailers(cfg: &LogsConfig, tx: mpsc::Sender<StreamLine>) -> Vec<JoinHandle<()>> {
+    cfg.sources
+        .iter()
+        .filter_map(|source| spawn_source(source, tx.clone()))
+        .collect()
+}
+
+fn spawn_source(source: &LogSourceConfig, tx: mpsc::Sender<StreamLine>) -> Option<JoinHandle<()>> {
+    if let Some(path) = &source.file {
+        return Some(tokio::spawn(tail_file(
+            source.name.clone(),
+            path.clone(),
+            tx,
+        )));
+    }
+
+    let unit = source.journal_unit.as_ref()?;
+    let mut args = Vec::new();
+    if source.user {
+        args.push("--user".to_string());
+    }
+    args.extend(
+        [
+            "--follow",
+            "--lines=50",
+            "--output=short-iso",
+            "--no-pager",
+            "--unit",
+        ]
+        .map(String::from),
+    );
+    args.push(unit.clone());
+    Some(spawn_command_lines(
+        source.name.clone(),
+        "journalctl".to_string(),
+        args,
+        tx,
+    ))
+}
+
+/// Stream stdout and stderr of a child process line by line until it exits or the
+/// receiver is dropped. The child is killed when the task is aborted.
+pub fn spawn_command_lines(
+    source: String,
+    cmd: String,
+    args: Vec<String>,
+    tx: mpsc::Sender<StreamLine>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let child = Command::new(&cmd)
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn();
+
+        let mut child = match child {
+            Ok(c) => c,
+            Err(e) => {
+                let _ = tx
+                    .send(StreamLine {
+                        source,
+                        text: format!("error: failed to start {cmd}: {e}"),
+                    })
+                    .await;
+                return;
+            }
+        };
+
+        let (Some(stdout), Some(stderr)) = (child.stdout.take(), child.stderr.take()) else {
+            return;
+        };
+        let mut out_lines = BufReader::new(stdout).lines();
+        let mut err_lines = BufReader::new(stderr).lines();
+        let mut out_open = true;
+        let mut err_open = true;
+
+        loop {
+            let line = tokio::select! {
+                line = out_lines.next_line(), if out_open => match line {
+                    Ok(Some(l)) => Some(l),
+                    _ => {
+                        out_open = false;
+                        None
+                    }
+                },
+                line = err_lines.next_line(), if err_open => match line {
+                    Ok(Some(l)) => Some(l),
+                    _ => {
+                        err_open = false;
+                        None
+                    }
+                },
+                else => break,
+            };
+
+            if let Some(text) = line
+                && tx
+                    .send(StreamLine {
+                        source: source.clone(),
+                        text,
+                    })
+                    .await
+                    .is_err()
+            {
+                return;
+            }
+        }
+
+        let status = child.wait().await;
+        let _ = tx
+            .send(StreamLine {
+                source,
+                text: match status {
+                    Ok(s) => format!("[{cmd} exited: {s}]"),
+                    Err(e) => format!("[{cmd} exited: {e}]"),
+                },
+            })
+            .await;
+    })
+}
+
+/// Follow a container's logs until the stream ends or the task is aborted; CLI-only runtimes use `<cli> logs -f`
+pub fn spawn_container_logs(
+    docker: DockerConfig,
+    id: String,
+    tail: Option<u32>,
+    since_secs: Option<u64>,
+    tag: String,
+    tx: mpsc::Sender<StreamLine>,
+) -> JoinHandle<()> {
+    let runtime = match Runtime::detect(&docker) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            return tokio::spawn(async move {
+                let _ = tx
+                    .send(StreamLine {
+                        source: tag,
+                        text: format!("error: {e}"),
+                    })
+                    .await;
+            });
+        }
+    };
+    let Some(endpoint) = runtime.endpoint else {
+        let mut args = vec![
+            "logs".to_string(),
+            "-f".to_string(),
+            "--timestamps".to_string(),
+        ];
+        args.push("--tail".to_string());
+        args.push(tail.map_or_else(|| "all".to_string(), |n| n.to_string()));
+        if let Some(secs) = since_secs {
+            args.push("--since".to_string());
+            args.push(format!("{secs}s"));
+        }
+        args.push(id);
+        return spawn_command_lines(tag, runtime.name().to_string(), args, tx);
+    };
+
+    tokio::spawn(async move {
+        let text = match follow_container_logs(&endpoint, &id, tail, since_secs, &tag, &tx).await {
+            Ok(()) => "[log stream ended]".to_string(),
+            Err(e) => format!("error: {e}"),
+        };
+        let _ = tx.send(StreamLine { source: tag, text }).await;
+    })
+}
+
+/// Subscribe to container events, reconnecting after the daemon goes away. CLI-only runtimes
+/// have no event stream and rely on polling alone.
+pub fn spawn_docker_events(docker: DockerConfig, tx: mpsc::Sender<DockerEvent>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            match Runtime::detect(&docker) {
+                Ok(Runtime {
+                    endpoint: Some(endpoint),
+                    ..
+                }) => {
+                    // Errors are already visible through the regular Docker collector
+                    let _ = follow_docker_events(&endpoint, &tx).await;
+                }
+                Ok(_) => return,
+                Err(_) => {}
+            }
+            if tx.is_closed() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        }
+    })
+}
+
+/// Poll a file for appended data, following truncation and rotation by size
+async fn tail_file(source: String, path: PathBuf, tx: mpsc::Sender<StreamLine>) {
+    let mut offset: Option<u64> = None;
+    let mut partial = String::new();
+    let mut reported_missing = false;
+
+    loop {
+        match read_appended(&path, &mut offset, &mut partial).await {
+            Ok(lines) => {
+                reported_missing = false;
+                for text in lines {
+                    if tx
+                        .send(StreamLine {
+                            source: source.clone(),
+                            text,
+                        })
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+            Err(e) => {
+                if !reported_missing {
+                    reported_missing = true;
+                    let text = format!("error: {}: {e}", path.display());
+                    if tx
+                        .send(StreamLine {
+                            source: source.clone(),
+                            text,
+                        })
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+                offset = None;
+            }
+        }
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
+
+async fn read_appended(
+    path: &Path,
+    offset: &mut Option<u64>,
+    partial: &mut String,
+) -> std::io::Result<Vec<String>> {
+    let mut file = File::open(path).await?;
+    let len = file.metadata().await?.len();
+
+    let start = match *offset {
+        // First read: start shortly before the end so there is some context
+        None => len.saturating_sub(FILE_BACKLOG_BYTES),
+        Some(prev) if prev > len => {
+            partial.clear();
+            0
+        }
+        Some(prev) => prev,
+    };
+    let skip_first = offset.is_none() && start > 0;
+
+    if start == len {
+        *offset = Some(len);
+        return Ok(vec![]);
+    }
+
+    file.seek(SeekFrom::Start(start)).await?;
+    let mut buf = Vec::with_capacity((len - start) as usize);
+    file.take(len - start).read_to_end(&mut buf).await?;
+    *offset = Some(start + buf.len() as u64);
+
+    partial.push_str(&String::from_utf8_lossy(&buf));
+    let mut lines = partial.split('\n').map(str::to_string).collect::<Vec<_>>();
+    // The last element is either empty or an incomplete line to carry over
+    *partial = lines.pop().unwrap_or_default();
+    if skip_first && !lines.is_empty() {
+        lines.remove(0);
+    }
+
+    Ok(lines
+        .into_iter()
+        .map(|l| l.trim_end_matches('\r').to_string())
+        .collect())
+}
@@ -134,6 +134,36 @@ pub fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
+    if app.filter_mode {
+        let filter = Paragraph::new(Line::from(vec![
+            Span::styled(
+                "/ ",
+                Style::default()
+                    .fg(ACCENT_BRIGHT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                app.filter_for(app.selected).to_string(),
+                Style::default().fg(TEXT),
+            ),
+            Span::styled("|", Style::default().fg(ACCENT_BRIGHT)),
+            Span::styled(
+                "   [Enter] keep  [Esc] clear",
+                Style::default().fg(TEXT_DIM),
+            ),
+        ]))
+        .alignment(Alignment::Left)
+        .style(Style::default().bg(BG))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(BORDER_FOCUSED)),
+        );
+        frame.render_widget(filter, area);
+        return;
+    }
+
     if app.compact_mode {
         let mut spans = vec![
             Span::styled("> ", Style::default().fg(ACCENT)),
@@ -175,6 +205,8 @@ pub fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
         Span::styled("Navigate  ", Style::default().fg(TEXT_DIM)),
         Span::styled("[Enter] ", Style::default().fg(ACCENT_BRIGHT)),
         Span::styled("Details  ", Style::default().fg(TEXT_DIM)),
+        Span::styled("[/] ", Style::default().fg(SECONDARY)),
+        Span::styled("Filter  ", Style::default().fg(TEXT_DIM)),
         Span::styled("[+/-]", Style::default().fg(GOOD)),
         Span::styled(" Resize  ", Style::default().fg(TEXT_DIM)),
         Span::styled("F10 ", Style::default().fg(BAD)),
@@ -280,8 +312,11 @@ fn pane_name(p: Pane) -> &'static str {
         Pane::System => "System",
         Pane::Prs => "Open PRs",
         Pane::Docker => "Docker",
-        Pane::Aws => "AWS EC2",
+        Pane::Aws => "AWS",
         Pane::Plugins => "Plugins",
+        Pane::Ports => "Ports",
+        Pane::Services => "Services",
+        Pane::Logs => "Logs",
     }
 }
 
@@ -293,6 +328,9 @@ fn pane_icon(p: Pane) -> &'static str {
         Pane::Docker => "[D]",
         Pane::Aws => "[A]",
         Pane::Plugins => "[X]",
+        Pane::Ports => "[N]",
+        Pane::Services => "[U]",
+        Pane::Logs => "[L]",
     }
 }
 
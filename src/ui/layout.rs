@@ -11,6 +11,9 @@ pub struct PaneLayout {
     pub docker: Rect,
     pub aws: Rect,
     pub plugins: Rect,
+    pub ports: Rect,
+    pub services: Rect,
+    pub logs: Rect,
     pub footer: Rect,
 }
 
@@ -29,7 +32,8 @@ pub fn compute_layout(area: Rect, layout: &LayoutState) -> PaneLayout {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Percentage(layout.top_height_pct),
-            Constraint::Percentage(100 - layout.top_height_pct),
+            Constraint::Percentage(100 - layout.top_height_pct - layout.extra_height_pct),
+            Constraint::Percentage(layout.extra_height_pct),
         ])
         .split(vertical[1]);
 
@@ -51,6 +55,15 @@ pub fn compute_layout(area: Rect, layout: &LayoutState) -> PaneLayout {
         ])
         .split(body[1]);
 
+    let extra = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(layout.extra_cols_pct[0]),
+            Constraint::Percentage(layout.extra_cols_pct[1]),
+            Constraint::Percentage(layout.extra_cols_pct[2]),
+        ])
+        .split(body[2]);
+
     PaneLayout {
         header: vertical[0],
         git: top[0],
@@ -59,6 +72,9 @@ pub fn compute_layout(area: Rect, layout: &LayoutState) -> PaneLayout {
         docker: bottom[0],
         aws: bottom[1],
         plugins: bottom[2],
+        ports: extra[0],
+        services: extra[1],
+        logs: extra[2],
         footer: vertical[2],
     }
 }
@@ -87,6 +103,15 @@ pub fn pane_at(area: Rect, layout: &LayoutState, x: u16, y: u16) -> Option<Pane>
     if point_in(map.plugins) {
         return Some(Pane::Plugins);
     }
+    if point_in(map.ports) {
+        return Some(Pane::Ports);
+    }
+    if point_in(map.services) {
+        return Some(Pane::Services);
+    }
+    if point_in(map.logs) {
+        return Some(Pane::Logs);
+    }
 
     None
 }
@@ -3,6 +3,7 @@ mod layout;
 mod modal;
 mod panes;
 mod theme;
+mod viewer;
 
 use ratatui::{Frame, layout::Margin, widgets::Block};
 
@@ -10,11 +11,13 @@ use crate::app::App;
 
 use self::chrome::{render_footer, render_header};
 use self::layout::compute_layout;
-use self::modal::render_modal;
+use self::modal::{render_confirm, render_modal};
 use self::panes::{
-    render_aws, render_docker, render_git, render_plugins, render_prs, render_system,
+    render_aws, render_docker, render_git, render_logs, render_plugins, render_ports, render_prs,
+    render_services, render_system,
 };
 use self::theme::BG;
+use self::viewer::render_log_viewer;
 
 pub use self::layout::pane_at;
 
@@ -35,9 +38,18 @@ pub fn render(frame: &mut Frame, app: &App) {
     render_docker(frame, app, layout.docker);
     render_aws(frame, app, layout.aws);
     render_plugins(frame, app, layout.plugins);
+    render_ports(frame, app, layout.ports);
+    render_services(frame, app, layout.services);
+    render_logs(frame, app, layout.logs);
     render_footer(frame, app, layout.footer);
 
     if let Some(detail) = &app.detail_modal {
         render_modal(frame, detail);
     }
+    if let Some(viewer) = &app.log_viewer {
+        render_log_viewer(frame, viewer);
+    }
+    if let Some(confirm) = &app.confirm {
+        render_confirm(frame, confirm, &app.confirm_input);
+    }
 }
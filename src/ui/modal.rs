@@ -6,9 +6,12 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
 };
 
-use crate::app::DetailModal;
+use crate::app::{ConfirmModal, DetailModal};
 
-use super::theme::{ACCENT_BRIGHT, BORDER_FOCUSED, GLOW, PANEL_BG, TEXT, TEXT_DIM};
+use super::theme::{
+    ACCENT_BRIGHT, BAD_BRIGHT, BORDER_FOCUSED, GLOW, GOOD_BRIGHT, PANEL_BG, TEXT, TEXT_DIM,
+    WARN_BRIGHT,
+};
 
 pub fn render_modal(frame: &mut Frame, detail: &DetailModal) {
     let popup = centered_rect(70, 50, frame.area());
@@ -64,6 +67,71 @@ pub fn render_modal(frame: &mut Frame, detail: &DetailModal) {
     frame.render_widget(modal, popup);
 }
 
+pub fn render_confirm(frame: &mut Frame, confirm: &ConfirmModal, input: &str) {
+    let popup = centered_rect(50, 30, frame.area());
+    frame.render_widget(Clear, popup);
+
+    let mut lines = vec![Line::from("")];
+    for line in &confirm.lines {
+        lines.push(Line::from(vec![
+            Span::styled("  ", Style::default()),
+            Span::styled(line.clone(), Style::default().fg(TEXT)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    let typed = confirm.action.confirm_phrase();
+    if let Some(phrase) = typed {
+        let color = if input == phrase { GOOD_BRIGHT } else { TEXT };
+        lines.push(Line::from(vec![
+            Span::styled("  > ", Style::default().fg(ACCENT_BRIGHT)),
+            Span::styled(input.to_string(), Style::default().fg(color)),
+            Span::styled("|", Style::default().fg(ACCENT_BRIGHT)),
+        ]));
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(vec![
+        Span::styled("  ", Style::default()),
+        Span::styled(
+            if typed.is_some() { "[Enter] " } else { "[y] " },
+            Style::default().fg(BAD_BRIGHT).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Confirm  ", Style::default().fg(TEXT_DIM)),
+        Span::styled(
+            if typed.is_some() {
+                "[Esc] "
+            } else {
+                "[n/Esc] "
+            },
+            Style::default().fg(GLOW).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Cancel", Style::default().fg(TEXT_DIM)),
+    ]));
+
+    let modal = Paragraph::new(lines)
+        .alignment(Alignment::Left)
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    format!(" [[ {} ]] ", confirm.title),
+                    Style::default()
+                        .fg(WARN_BRIGHT)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(
+                    Style::default()
+                        .fg(WARN_BRIGHT)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .style(Style::default().bg(PANEL_BG)),
+        )
+        .wrap(Wrap { trim: false })
+        .style(Style::default().fg(TEXT));
+
+    frame.render_widget(modal, popup);
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
     let vertical = Layout::default()
         .direction(Direction::Vertical)
@@ -7,7 +7,13 @@ use ratatui::{
     widgets::{Gauge, List, ListItem, ListState, Paragraph, Sparkline, Wrap},
 };
 
-use crate::app::{App, Pane, SystemLayoutMode};
+use crate::app::{
+    App, AwsGroup, AwsRow, ComposeProject, DockerRow, DockerSort, LogLine, Pane, PrSort, Severity,
+    StorageRow, SystemAlerts, SystemLayoutMode,
+};
+use crate::collectors::{
+    AwsInstance, AwsResource, CpuCore, DockerContainer, PrItem, PsiAverages, SystemStatus,
+};
 
 use super::chrome::pane_block;
 use super::theme::{
@@ -166,6 +172,11 @@ pub fn render_system(frame: &mut Frame, app: &App, area: Rect) {
     let inner = panel.inner(area);
     frame.render_widget(panel, area);
 
+    if app.system_core_view {
+        render_core_heatmap(frame, inner, app);
+        return;
+    }
+
     if mode == SystemLayoutMode::Compact {
         render_system_compact(
             frame,
@@ -195,6 +206,7 @@ pub fn render_system(frame: &mut Frame, app: &App, area: Rect) {
             Constraint::Length(1), // mem trend
             Constraint::Length(1), // disk trend (NEW)
             Constraint::Length(1), // core meters
+            Constraint::Length(1), // pressure / cgroup
             Constraint::Length(1), // trend strips
             Constraint::Length(1), // process line
         ])
@@ -443,6 +455,9 @@ pub fn render_system(frame: &mut Frame, app: &App, area: Rect) {
     ]));
     frame.render_widget(core_line, rows[5]);
 
+    let pressure = Paragraph::new(pressure_line(sys, &app.system_alerts));
+    frame.render_widget(pressure, rows[6]);
+
     let footer = Paragraph::new(Line::from(vec![
         Span::styled("C ", Style::default().fg(cpu_color)),
         Span::styled(cpu_strip, Style::default().fg(cpu_color)),
@@ -451,7 +466,7 @@ pub fn render_system(frame: &mut Frame, app: &App, area: Rect) {
         Span::styled("  D ", Style::default().fg(disk_color)),
         Span::styled(disk_strip, Style::default().fg(disk_color)),
     ]));
-    frame.render_widget(footer, rows[6]);
+    frame.render_widget(footer, rows[7]);
 
     let proc_line = Paragraph::new(render_top_process_line(
         &sys.top_processes,
@@ -459,7 +474,7 @@ pub fn render_system(frame: &mut Frame, app: &App, area: Rect) {
         app.current_list_cursor(Pane::System),
         app.selected == Pane::System,
     ));
-    frame.render_widget(proc_line, rows[7]);
+    frame.render_widget(proc_line, rows[8]);
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -487,6 +502,7 @@ fn render_system_compact(
             Constraint::Length(2),
             Constraint::Length(1),
             Constraint::Length(1),
+            Constraint::Length(1),
         ])
         .split(area);
 
@@ -570,42 +586,633 @@ fn render_system_compact(
         ),
     ]));
     frame.render_widget(footer, rows[4]);
+
+    if app.data.system.pressure.is_some() || app.data.system.cgroup.is_some() {
+        let pressure = Paragraph::new(pressure_line(&app.data.system, &app.system_alerts));
+        frame.render_widget(pressure, rows[5]);
+    }
 }
 
 pub fn render_docker(frame: &mut Frame, app: &App, area: Rect) {
-    render_status_list(
-        frame,
-        area,
-        pane_block("DOCKER", app.selected == Pane::Docker),
-        app.data.docker.running.iter().map(|s| s.as_str()).collect(),
-        app.data.docker.error.as_deref(),
-        None,
-        app.current_list_cursor(Pane::Docker),
+    if app.docker_storage_view {
+        render_docker_storage(frame, app, area);
+        return;
+    }
+    let docker = &app.data.docker;
+    let visible = app.visible_containers();
+    let running = docker.items.iter().filter(|c| c.state == "running").count();
+    let runtime = match docker.runtime.as_str() {
+        "" => "DOCKER".to_string(),
+        name => name.to_uppercase(),
+    };
+    let mut title = format!(
+        "{runtime} {} ({}/{}, {running} up)",
+        app.docker_filter.label(),
+        visible.len(),
+        docker.items.len()
     );
+    if app.docker_sort != DockerSort::Default {
+        title.push_str(&format!(" by {}", app.docker_sort.label()));
+    }
+    let block = pane_block(&title, app.selected == Pane::Docker);
+
+    let mut list_items = Vec::new();
+    if let Some(msg) = &docker.error {
+        list_items.push(ListItem::new(Line::from(Span::styled(
+            format!("error: {msg}"),
+            Style::default().fg(BAD),
+        ))));
+    } else if visible.is_empty() {
+        list_items.push(ListItem::new(Line::from(Span::styled(
+            format!("no {} containers", app.docker_filter.label()),
+            Style::default().fg(MUTED),
+        ))));
+    }
+    let offset = list_items.len();
+
+    list_items.extend(app.docker_rows().iter().map(|row| match row {
+        DockerRow::Project(project) => project_row(project),
+        DockerRow::Container { container, grouped } => container_row(app, container, *grouped),
+    }));
+
+    let mut state = ListState::default();
+    state.select(app.current_list_cursor(Pane::Docker).map(|v| v + offset));
+
+    let list = List::new(list_items)
+        .block(block)
+        .highlight_symbol("▶ ")
+        .highlight_style(Style::default().fg(TEXT).bg(HIGHLIGHT_BG));
+    frame.render_stateful_widget(list, area, &mut state);
 }
 
-pub fn render_aws(frame: &mut Frame, app: &App, area: Rect) {
-    render_status_list(
-        frame,
-        area,
-        pane_block("AWS EC2", app.selected == Pane::Aws),
-        app.data.aws.instances.iter().map(|s| s.as_str()).collect(),
-        app.data.aws.error.as_deref(),
-        Some(app.data.aws.source.as_str()),
-        app.current_list_cursor(Pane::Aws),
+/// Images, volumes and the `system df` summary; each row is tagged with its kind
+fn render_docker_storage(frame: &mut Frame, app: &App, area: Rect) {
+    let docker = &app.data.docker;
+    let storage = &docker.storage;
+    let runtime = match docker.runtime.as_str() {
+        "" => "DOCKER".to_string(),
+        name => name.to_uppercase(),
+    };
+    let reclaimable: u64 = storage.usage.iter().map(|u| u.reclaimable_bytes).sum();
+    let title = format!(
+        "{runtime} storage ({} images, {} volumes, {} reclaimable)",
+        storage.images.len(),
+        storage.volumes.len(),
+        compact_bytes(reclaimable)
     );
+    let block = pane_block(&title, app.selected == Pane::Docker);
+
+    let mut list_items = Vec::new();
+    if let Some(msg) = docker.error.as_ref().or(storage.error.as_ref()) {
+        list_items.push(ListItem::new(Line::from(Span::styled(
+            format!("error: {msg}"),
+            Style::default().fg(BAD),
+        ))));
+    }
+    let offset = list_items.len();
+
+    list_items.extend(app.storage_rows().into_iter().map(|row| {
+        let spans = match row {
+            StorageRow::Usage(usage) => vec![
+                Span::styled("df  ", Style::default().fg(ACCENT)),
+                Span::styled(
+                    format!("{:<12}", usage.kind),
+                    Style::default().fg(TEXT).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:>4} ({:>3} active) ", usage.total, usage.active),
+                    Style::default().fg(TEXT_DIM),
+                ),
+                Span::styled(
+                    format!("{:>5} ", compact_bytes(usage.size_bytes)),
+                    Style::default().fg(TEXT),
+                ),
+                Span::styled(
+                    format!("{:>5} reclaimable", compact_bytes(usage.reclaimable_bytes)),
+                    Style::default().fg(if usage.reclaimable_bytes > 0 {
+                        WARN_BRIGHT
+                    } else {
+                        MUTED
+                    }),
+                ),
+            ],
+            StorageRow::Image(image) => {
+                let (name, color) = if image.dangling {
+                    (format!("<none> {}", image.id), WARN_BRIGHT)
+                } else {
+                    (image.tags.join(" "), TEXT)
+                };
+                vec![
+                    Span::styled("img ", Style::default().fg(SECONDARY)),
+                    Span::styled(
+                        format!("{:<32} ", truncate_name(&name, 32)),
+                        Style::default().fg(color),
+                    ),
+                    Span::styled(
+                        format!("{:>5} ", compact_bytes(image.size_bytes)),
+                        Style::default().fg(TEXT),
+                    ),
+                    if image.dangling {
+                        Span::styled("dangling", Style::default().fg(WARN_BRIGHT))
+                    } else if image.containers > 0 {
+                        Span::styled(
+                            format!("{} containers", image.containers),
+                            Style::default().fg(GOOD_BRIGHT),
+                        )
+                    } else {
+                        Span::styled("unused", Style::default().fg(MUTED))
+                    },
+                ]
+            }
+            StorageRow::Volume(volume) => vec![
+                Span::styled("vol ", Style::default().fg(TERTIARY)),
+                Span::styled(
+                    format!("{:<32} ", truncate_name(&volume.name, 32)),
+                    Style::default().fg(TEXT),
+                ),
+                Span::styled(
+                    format!(
+                        "{:>5} ",
+                        volume.size_bytes.map_or("n/a".to_string(), compact_bytes)
+                    ),
+                    Style::default().fg(TEXT),
+                ),
+                if volume.ref_count > 0 {
+                    Span::styled("in use", Style::default().fg(GOOD_BRIGHT))
+                } else {
+                    Span::styled("unused", Style::default().fg(MUTED))
+                },
+            ],
+        };
+        ListItem::new(Line::from(spans))
+    }));
+
+    let mut state = ListState::default();
+    state.select(app.current_list_cursor(Pane::Docker).map(|v| v + offset));
+
+    let list = List::new(list_items)
+        .block(block)
+        .highlight_symbol("▶ ")
+        .highlight_style(Style::default().fg(TEXT).bg(HIGHLIGHT_BG));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn project_row(project: &ComposeProject) -> ListItem<'static> {
+    let (badge, color) = if project.failing > 0 {
+        ("✖", BAD_BRIGHT)
+    } else if project.running < project.total {
+        ("◐", WARN_BRIGHT)
+    } else {
+        ("●", GOOD_BRIGHT)
+    };
+    let mut spans = vec![
+        Span::styled(
+            if project.collapsed { "▸ " } else { "▾ " },
+            Style::default().fg(ACCENT),
+        ),
+        Span::styled(
+            format!("{} ", project.name),
+            Style::default().fg(TEXT).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{badge} {}/{} up", project.running, project.total),
+            Style::default().fg(color),
+        ),
+    ];
+    if project.failing > 0 {
+        spans.push(Span::styled(
+            format!(" {} failing", project.failing),
+            Style::default().fg(BAD_BRIGHT),
+        ));
+    }
+    ListItem::new(Line::from(spans))
+}
+
+fn container_row<'a>(app: &App, c: &'a DockerContainer, grouped: bool) -> ListItem<'a> {
+    let (badge, color) = container_badge(c);
+    // Inside a project the service name is more telling than `project-service-1`
+    let name = if grouped {
+        c.compose_service().unwrap_or(&c.name)
+    } else {
+        &c.name
+    };
+    let width = if grouped { 16 } else { 18 };
+    let mut spans = vec![
+        Span::raw(if grouped { "  " } else { "" }),
+        Span::styled(format!("{badge} "), Style::default().fg(color)),
+        Span::styled(
+            format!("{:<width$} ", truncate_name(name, width)),
+            Style::default().fg(if c.needs_attention() {
+                BAD_BRIGHT
+            } else {
+                TEXT
+            }),
+        ),
+        Span::styled(format!("{:<10}", c.state), Style::default().fg(color)),
+    ];
+    if let Some(stats) = &c.stats {
+        let cpu = stats.cpu_pct.unwrap_or(0.0) as f32;
+        spans.push(Span::styled(
+            format!("{cpu:>5.1}% "),
+            Style::default().fg(heat_color(cpu, &app.system_alerts)),
+        ));
+        spans.push(Span::styled(
+            format!("{:>5} ", compact_bytes(stats.mem_used_bytes)),
+            Style::default().fg(TEXT),
+        ));
+        spans.push(Span::styled(
+            format!(
+                "{:>5} ",
+                compact_bytes(stats.net_rx_bytes + stats.net_tx_bytes)
+            ),
+            Style::default().fg(ACCENT),
+        ));
+        spans.push(Span::styled(
+            format!(
+                "{:>5}",
+                compact_bytes(stats.blk_read_bytes + stats.blk_write_bytes)
+            ),
+            Style::default().fg(SECONDARY),
+        ));
+    }
+    if let Some(code) = c.exit_code.filter(|_| c.state == "exited") {
+        spans.push(Span::styled(
+            format!(" exit {code}"),
+            Style::default().fg(if code == 0 { MUTED } else { BAD_BRIGHT }),
+        ));
+    }
+    match c.health.as_deref() {
+        Some("unhealthy") => {
+            spans.push(Span::styled(" unhealthy", Style::default().fg(BAD_BRIGHT)))
+        }
+        Some("starting") => spans.push(Span::styled(" starting", Style::default().fg(WARN_BRIGHT))),
+        _ => {}
+    }
+    spans.push(Span::styled(
+        format!(" {}", c.image),
+        Style::default().fg(TEXT_DIM),
+    ));
+    ListItem::new(Line::from(spans))
+}
+
+/// Four-character byte count: `912B`, `34K`, `1.2G`
+fn compact_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit > 0 && value < 10.0 {
+        format!("{value:.1}{}", UNITS[unit])
+    } else {
+        format!("{value:.0}{}", UNITS[unit])
+    }
+}
+
+fn container_badge(c: &DockerContainer) -> (&'static str, Color) {
+    match c.state.as_str() {
+        "running" if c.health.as_deref() == Some("unhealthy") => ("●", BAD_BRIGHT),
+        "running" => ("●", GOOD_BRIGHT),
+        "restarting" => ("↻", WARN_BRIGHT),
+        "paused" => ("‖", SECONDARY),
+        "dead" => ("✖", BAD_BRIGHT),
+        "exited" if c.exit_code.is_some_and(|code| code != 0) => ("✖", BAD),
+        _ => ("○", MUTED),
+    }
+}
+
+pub fn render_aws(frame: &mut Frame, app: &App, area: Rect) {
+    let aws = &app.data.aws;
+    let view = app.aws_view;
+    let rows = app.aws_rows();
+    let filter = app.filter_for(Pane::Aws);
+    let mut count = match view {
+        AwsResource::Ec2 => aws.items.len(),
+        AwsResource::Ecs => aws.ecs.len(),
+        AwsResource::Lambda => aws.lambdas.len(),
+        AwsResource::Rds => aws.databases.len(),
+        AwsResource::Alarms => aws.alarms.len(),
+    }
+    .to_string();
+    if !filter.is_empty() {
+        let visible = rows
+            .iter()
+            .filter(|r| !matches!(r, AwsRow::Group(_)))
+            .count();
+        count = format!("{visible}/{count}");
+    }
+    let failed = aws
+        .targets
+        .iter()
+        .filter(|t| t.error(view).is_some())
+        .count();
+    let mut title = if filter.is_empty() {
+        format!("AWS {} ({count}", view.label())
+    } else {
+        format!("AWS {} /{filter} ({count}", view.label())
+    };
+    if aws.targets.len() > 1 {
+        title.push_str(&format!(
+            ", {}/{} targets ok",
+            aws.targets.len() - failed,
+            aws.targets.len()
+        ));
+    }
+    title.push(')');
+    let block = pane_block(&title, app.selected == Pane::Aws);
+
+    let mut list_items = vec![ListItem::new(Line::from(vec![
+        Span::styled("source: ", Style::default().fg(MUTED)),
+        Span::styled(aws.source.clone(), Style::default().fg(TEXT)),
+    ]))];
+    // With targets listed, their headers carry the errors
+    if let Some(msg) = aws.error.as_ref().filter(|_| aws.targets.is_empty()) {
+        list_items.push(ListItem::new(Line::from(Span::styled(
+            format!("error: {msg}"),
+            Style::default().fg(BAD),
+        ))));
+    }
+    let offset = list_items.len();
+
+    list_items.extend(rows.iter().map(|row| match row {
+        AwsRow::Group(group) => aws_group_row(group, view),
+        AwsRow::Instance(instance) => aws_instance_row(instance),
+        AwsRow::Service(svc) => {
+            let (badge, color) = aws_badge(view, svc.health());
+            let mut spans = vec![
+                Span::styled(format!("  {badge} "), Style::default().fg(color)),
+                Span::styled(
+                    format!("{:<20} ", truncate_name(&svc.name, 20)),
+                    Style::default().fg(TEXT),
+                ),
+                Span::styled(
+                    format!("{}/{} up ", svc.running, svc.desired),
+                    Style::default().fg(color),
+                ),
+            ];
+            if svc.pending > 0 {
+                spans.push(Span::styled(
+                    format!("{} pending ", svc.pending),
+                    Style::default().fg(WARN_BRIGHT),
+                ));
+            }
+            spans.push(Span::styled(
+                svc.cluster.clone(),
+                Style::default().fg(TEXT_DIM),
+            ));
+            ListItem::new(Line::from(spans))
+        }
+        AwsRow::Function(f) => ListItem::new(Line::from(vec![
+            Span::styled("  λ ", Style::default().fg(ACCENT)),
+            Span::styled(
+                format!("{:<24} ", truncate_name(&f.name, 24)),
+                Style::default().fg(TEXT),
+            ),
+            Span::styled(
+                format!("{:<12} ", f.runtime),
+                Style::default().fg(SECONDARY),
+            ),
+            Span::styled(
+                short_aws_time(&f.last_modified),
+                Style::default().fg(TEXT_DIM),
+            ),
+        ])),
+        AwsRow::Database(d) => {
+            let (badge, color) = aws_badge(view, &d.status);
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("  {badge} "), Style::default().fg(color)),
+                Span::styled(
+                    format!("{:<20} ", truncate_name(&d.id, 20)),
+                    Style::default().fg(TEXT),
+                ),
+                Span::styled(format!("{:<10} ", d.status), Style::default().fg(color)),
+                Span::styled(
+                    format!("{} {} ", d.engine, d.engine_version),
+                    Style::default().fg(SECONDARY),
+                ),
+                Span::styled(d.class.clone(), Style::default().fg(TEXT_DIM)),
+            ]))
+        }
+        AwsRow::Alarm(a) => ListItem::new(Line::from(vec![
+            Span::styled("  ✖ ", Style::default().fg(BAD_BRIGHT)),
+            Span::styled(
+                format!("{:<24} ", truncate_name(&a.name, 24)),
+                Style::default().fg(TEXT),
+            ),
+            Span::styled(
+                format!("{}/{} ", a.namespace, a.metric),
+                Style::default().fg(SECONDARY),
+            ),
+            Span::styled(
+                format!("since {}", short_aws_time(&a.since)),
+                Style::default().fg(TEXT_DIM),
+            ),
+        ])),
+    }));
+
+    let mut state = ListState::default();
+    state.select(app.current_list_cursor(Pane::Aws).map(|v| v + offset));
+
+    let list = List::new(list_items)
+        .block(block)
+        .highlight_symbol("▶ ")
+        .highlight_style(Style::default().fg(TEXT).bg(HIGHLIGHT_BG));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn aws_group_row(group: &AwsGroup<'_>, view: AwsResource) -> ListItem<'static> {
+    let mut spans = vec![Span::styled(
+        format!("{} ", group.label()),
+        Style::default().fg(TEXT).add_modifier(Modifier::BOLD),
+    )];
+    if group.states.is_empty() && group.error.is_none() {
+        let empty = match view {
+            AwsResource::Ec2 => "no instances",
+            AwsResource::Ecs => "no services",
+            AwsResource::Lambda => "no functions",
+            AwsResource::Rds => "no databases",
+            AwsResource::Alarms => "no alarms firing",
+        };
+        spans.push(Span::styled(empty, Style::default().fg(MUTED)));
+    }
+    for (state, count) in &group.states {
+        let (badge, color) = aws_badge(view, state);
+        spans.push(Span::styled(
+            format!("{badge} {count} {state} "),
+            Style::default().fg(color),
+        ));
+    }
+    if let Some(err) = group.error {
+        spans.push(Span::styled(
+            format!("✖ {err}"),
+            Style::default().fg(BAD_BRIGHT),
+        ));
+    }
+    ListItem::new(Line::from(spans))
+}
+
+fn aws_instance_row(instance: &AwsInstance) -> ListItem<'_> {
+    let (badge, color) = aws_badge(AwsResource::Ec2, &instance.state);
+    ListItem::new(Line::from(vec![
+        Span::styled(format!("  {badge} "), Style::default().fg(color)),
+        Span::styled(
+            format!("{:<18} ", truncate_name(&instance.name, 18)),
+            Style::default().fg(TEXT),
+        ),
+        Span::styled(
+            format!("{:<10} ", instance.state),
+            Style::default().fg(color),
+        ),
+        Span::styled(format!("{} ", instance.id), Style::default().fg(TEXT_DIM)),
+        Span::styled(
+            instance.instance_type.clone(),
+            Style::default().fg(SECONDARY),
+        ),
+    ]))
+}
+
+/// Badge for an EC2 state, ECS health, Lambda runtime, RDS status or firing alarm
+fn aws_badge(view: AwsResource, state: &str) -> (&'static str, Color) {
+    match (view, state) {
+        (AwsResource::Lambda, _) => ("λ", ACCENT),
+        (AwsResource::Alarms, _) => ("✖", BAD_BRIGHT),
+        (_, "running" | "steady" | "available") => ("●", GOOD_BRIGHT),
+        (_, "degraded") => ("◐", WARN_BRIGHT),
+        (_, "failed" | "storage-full" | "inaccessible-encryption-credentials") => ("✖", BAD_BRIGHT),
+        (_, "terminated" | "stopped") => ("○", MUTED),
+        (AwsResource::Rds, _) => ("◐", WARN_BRIGHT),
+        (_, "pending" | "stopping" | "shutting-down" | "rebooting") => ("◐", WARN_BRIGHT),
+        _ => ("○", MUTED),
+    }
+}
+
+/// `2024-05-01T10:00:00.000+0000` -> `2024-05-01 10:00`
+fn short_aws_time(raw: &str) -> String {
+    raw.chars()
+        .take(16)
+        .map(|c| if c == 'T' { ' ' } else { c })
+        .collect()
 }
 
 pub fn render_prs(frame: &mut Frame, app: &App, area: Rect) {
-    render_status_list(
-        frame,
-        area,
-        pane_block("OPEN PRS", app.selected == Pane::Prs),
-        app.data.prs.open.iter().map(|s| s.as_str()).collect(),
-        app.data.prs.error.as_deref(),
-        Some(app.data.prs.source.as_str()),
-        app.current_list_cursor(Pane::Prs),
-    );
+    let prs = &app.data.prs;
+    let items = app.pr_rows();
+    // Every filter's total, the active one bracketed: `OPEN PRS (all 12 [mine 3] review 1)`
+    let counts = prs
+        .views
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            if i == app.pr_view {
+                format!("[{} {}]", v.name, v.total)
+            } else {
+                format!("{} {}", v.name, v.total)
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut title = if counts.is_empty() {
+        "OPEN PRS".to_string()
+    } else {
+        format!("OPEN PRS ({})", counts.join(" "))
+    };
+    if app.pr_sort != PrSort::default() {
+        title.push_str(&format!(" by {}", app.pr_sort.label()));
+    }
+    let block = pane_block(&title, app.selected == Pane::Prs);
+
+    let mut list_items = vec![ListItem::new(Line::from(vec![
+        Span::styled("source: ", Style::default().fg(MUTED)),
+        Span::styled(prs.source.clone(), Style::default().fg(TEXT)),
+    ]))];
+    if let Some(msg) = &prs.error {
+        list_items.push(ListItem::new(Line::from(Span::styled(
+            format!("error: {msg}"),
+            Style::default().fg(BAD),
+        ))));
+    } else if items.is_empty() {
+        list_items.push(ListItem::new(Line::from(Span::styled(
+            "no open PRs match",
+            Style::default().fg(MUTED),
+        ))));
+    }
+    let offset = list_items.len();
+    list_items.extend(items.into_iter().map(pr_row));
+
+    let mut state = ListState::default();
+    state.select(app.current_list_cursor(Pane::Prs).map(|v| v + offset));
+
+    let list = List::new(list_items)
+        .block(block)
+        .highlight_symbol("▶ ")
+        .highlight_style(Style::default().fg(TEXT).bg(HIGHLIGHT_BG));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Title with the CI badge, then author and review/merge/size badges underneath
+fn pr_row(pr: &PrItem) -> ListItem<'_> {
+    let (ci, ci_color) = match pr.checks.as_deref() {
+        Some("success") => ("●", GOOD_BRIGHT),
+        Some("failure") => ("✖", BAD_BRIGHT),
+        Some(_) => ("◐", WARN_BRIGHT),
+        None => ("○", MUTED),
+    };
+    let title = Line::from(vec![
+        Span::styled(format!("{ci} "), Style::default().fg(ci_color)),
+        Span::styled(format!("#{} ", pr.number), Style::default().fg(ACCENT)),
+        Span::styled(
+            pr.title.as_str(),
+            Style::default().fg(if pr.draft { TEXT_DIM } else { TEXT }),
+        ),
+    ]);
+
+    let mut badges = vec![
+        Span::styled(
+            format!("  {} ", pr.repo),
+            Style::default().fg(ACCENT_BRIGHT),
+        ),
+        Span::styled(format!("@{} ", pr.author), Style::default().fg(TEXT_DIM)),
+    ];
+    if pr.draft {
+        badges.push(Span::styled("draft ", Style::default().fg(MUTED)));
+    }
+    match pr.review_decision.as_deref() {
+        Some("approved") => badges.push(Span::styled(
+            "✔ approved ",
+            Style::default().fg(GOOD_BRIGHT),
+        )),
+        Some("changes requested") => {
+            badges.push(Span::styled("✖ changes ", Style::default().fg(BAD_BRIGHT)))
+        }
+        Some(_) => badges.push(Span::styled("◐ review ", Style::default().fg(WARN_BRIGHT))),
+        None => {}
+    }
+    if !pr.requested_reviewers.is_empty() {
+        badges.push(Span::styled(
+            format!("→{} ", pr.requested_reviewers.join(",")),
+            Style::default().fg(SECONDARY),
+        ));
+    }
+    if pr.mergeable == "conflicting" {
+        badges.push(Span::styled("⚠ conflict ", Style::default().fg(BAD)));
+    }
+    badges.push(Span::styled(
+        format!("+{} ", pr.additions),
+        Style::default().fg(GOOD),
+    ));
+    badges.push(Span::styled(
+        format!("-{} ", pr.deletions),
+        Style::default().fg(BAD),
+    ));
+    for label in &pr.labels {
+        badges.push(Span::styled(
+            format!("[{label}] "),
+            Style::default().fg(TERTIARY),
+        ));
+    }
+
+    ListItem::new(vec![title, Line::from(badges)])
 }
 
 pub fn render_plugins(frame: &mut Frame, app: &App, area: Rect) {
@@ -676,40 +1283,121 @@ pub fn render_plugins(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_stateful_widget(list, area, &mut state);
 }
 
-fn render_status_list(
-    frame: &mut Frame,
-    area: Rect,
-    block: ratatui::widgets::Block<'_>,
-    items: Vec<&str>,
-    err: Option<&str>,
-    source: Option<&str>,
-    selected: Option<usize>,
-) {
-    let mut list_items = Vec::new();
-    if let Some(s) = source {
-        list_items.push(ListItem::new(Line::from(vec![
-            Span::styled("source: ", Style::default().fg(MUTED)),
-            Span::styled(s.to_string(), Style::default().fg(TEXT)),
-        ])));
+pub fn render_ports(frame: &mut Frame, app: &App, area: Rect) {
+    let visible = app.visible_ports();
+    let filter = app.filter_for(Pane::Ports);
+    let title = if filter.is_empty() {
+        format!("PORTS ({})", app.data.ports.items.len())
+    } else {
+        format!(
+            "PORTS /{filter} ({}/{})",
+            visible.len(),
+            app.data.ports.items.len()
+        )
+    };
+    let block = pane_block(&title, app.selected == Pane::Ports);
+
+    if let Some(err) = &app.data.ports.error {
+        let p = Paragraph::new(Line::from(Span::styled(
+            format!("error: {err}"),
+            Style::default().fg(BAD),
+        )))
+        .block(block);
+        frame.render_widget(p, area);
+        return;
     }
 
-    if let Some(msg) = err {
-        list_items.push(ListItem::new(Line::from(vec![Span::styled(
+    let items = visible
+        .iter()
+        .map(|s| {
+            let proto_color = if s.protocol.starts_with("tcp") {
+                ACCENT_BRIGHT
+            } else {
+                SECONDARY
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:>5} ", s.port),
+                    Style::default().fg(TEXT).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:<5}", s.protocol),
+                    Style::default().fg(proto_color),
+                ),
+                Span::styled(
+                    format!("{:<16} ", truncate_name(&s.local_addr, 16)),
+                    Style::default().fg(TEXT_DIM),
+                ),
+                Span::styled(
+                    format!(
+                        "{:>7} ",
+                        s.pid.map_or_else(|| "-".to_string(), |p| p.to_string())
+                    ),
+                    Style::default().fg(MUTED),
+                ),
+                Span::styled(
+                    s.process.clone(),
+                    Style::default().fg(if s.pid.is_some() { GOOD_BRIGHT } else { MUTED }),
+                ),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    let mut state = ListState::default();
+    state.select(app.current_list_cursor(Pane::Ports));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_symbol("▶ ")
+        .highlight_style(Style::default().fg(TEXT).bg(HIGHLIGHT_BG));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+pub fn render_services(frame: &mut Frame, app: &App, area: Rect) {
+    let services = &app.data.services;
+    let failed = services.items.iter().filter(|u| u.is_failed()).count();
+    let title = if failed > 0 {
+        format!("SERVICES ({}, {failed} failed)", services.items.len())
+    } else {
+        format!("SERVICES ({})", services.items.len())
+    };
+    let block = pane_block(&title, app.selected == Pane::Services);
+
+    let mut list_items = Vec::new();
+    if let Some(msg) = &services.error {
+        list_items.push(ListItem::new(Line::from(Span::styled(
             format!("error: {msg}"),
             Style::default().fg(BAD),
-        )])));
+        ))));
     }
-
     let offset = list_items.len();
-    list_items.extend(items.into_iter().map(|line| {
-        ListItem::new(Line::from(vec![
-            Span::styled("- ", Style::default().fg(ACCENT)),
-            Span::styled(line.to_string(), Style::default().fg(TEXT)),
-        ]))
+
+    list_items.extend(services.items.iter().map(|u| {
+        let (glyph, color) = match u.active.as_str() {
+            "failed" => ("✖", BAD_BRIGHT),
+            "active" => ("●", GOOD_BRIGHT),
+            "activating" | "deactivating" | "reloading" => ("◐", WARN_BRIGHT),
+            _ => ("○", MUTED),
+        };
+        let mut spans = vec![
+            Span::styled(format!("{glyph} "), Style::default().fg(color)),
+            Span::styled(
+                format!("{:<28} ", truncate_name(&u.name, 28)),
+                Style::default().fg(if u.is_failed() { BAD_BRIGHT } else { TEXT }),
+            ),
+            Span::styled(
+                format!("{}/{}", u.active, u.sub),
+                Style::default().fg(color),
+            ),
+        ];
+        if u.user {
+            spans.push(Span::styled(" user", Style::default().fg(SECONDARY)));
+        }
+        ListItem::new(Line::from(spans))
     }));
 
     let mut state = ListState::default();
-    state.select(selected.map(|v| v + offset));
+    state.select(app.current_list_cursor(Pane::Services).map(|v| v + offset));
 
     let list = List::new(list_items)
         .block(block)
@@ -718,6 +1406,79 @@ fn render_status_list(
     frame.render_stateful_widget(list, area, &mut state);
 }
 
+pub fn render_logs(frame: &mut Frame, app: &App, area: Rect) {
+    let visible = app.visible_logs();
+    let source = app.log_source.as_deref().unwrap_or("all");
+    let search = app.filters.logs.trim();
+
+    let mut title = format!("LOGS {source} ({})", visible.len());
+    if !search.is_empty() {
+        title.push_str(&format!(" /{search}"));
+    }
+    if app.logs.paused {
+        title.push_str(&format!(" PAUSED -{}", app.logs.scroll));
+    }
+    let block = pane_block(&title, app.selected == Pane::Logs);
+
+    let lines = if app.log_sources.is_empty() {
+        vec![Line::from(Span::styled(
+            "no [logs] sources configured",
+            Style::default().fg(MUTED),
+        ))]
+    } else {
+        let height = area.height.saturating_sub(2) as usize;
+        let end = visible.len().saturating_sub(app.logs.scroll);
+        let start = end.saturating_sub(height);
+        let show_source = app.log_source.is_none() && app.log_sources.len() > 1;
+        visible[start..end]
+            .iter()
+            .map(|l| log_line(l, show_source, search))
+            .collect()
+    };
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+pub(super) fn log_line<'a>(line: &'a LogLine, show_source: bool, search: &str) -> Line<'a> {
+    let style = Style::default().fg(match line.severity {
+        Severity::Error => BAD_BRIGHT,
+        Severity::Warn => WARN_BRIGHT,
+        Severity::Debug => MUTED,
+        Severity::Plain => TEXT,
+    });
+
+    let mut spans = Vec::new();
+    if show_source {
+        spans.push(Span::styled(
+            format!("{:<8}│ ", truncate_name(&line.source, 8)),
+            Style::default().fg(SECONDARY),
+        ));
+    }
+
+    if search.is_empty() {
+        spans.push(Span::styled(line.text.as_str(), style));
+        return Line::from(spans);
+    }
+
+    // ASCII lowercasing keeps byte offsets aligned with the original text
+    let needle = search.to_ascii_lowercase();
+    let haystack = line.text.to_ascii_lowercase();
+    let mut pos = 0;
+    for (idx, _) in haystack.match_indices(&needle) {
+        if idx < pos {
+            continue;
+        }
+        spans.push(Span::styled(&line.text[pos..idx], style));
+        spans.push(Span::styled(
+            &line.text[idx..idx + needle.len()],
+            Style::default().fg(Color::Black).bg(WARN_BRIGHT),
+        ));
+        pos = idx + needle.len();
+    }
+    spans.push(Span::styled(&line.text[pos..], style));
+    Line::from(spans)
+}
+
 fn blank_to_na(s: &str) -> &str {
     if s.trim().is_empty() { "n/a" } else { s }
 }
@@ -882,7 +1643,7 @@ fn orbit_dots(width: usize, phase: usize) -> String {
     out.into_iter().collect()
 }
 
-fn core_meter(cores: &[f32], width: usize) -> String {
+fn core_meter(cores: &[CpuCore], width: usize) -> String {
     const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
     if width == 0 {
         return String::new();
@@ -891,20 +1652,243 @@ fn core_meter(cores: &[f32], width: usize) -> String {
         return "n/a".to_string();
     }
 
-    let mut out = String::new();
-    let step = (cores.len() as f32 / width as f32).max(1.0);
-    let mut idx = 0.0;
-    while out.len() < width {
-        let cidx = idx as usize;
-        let v = *cores.get(cidx).unwrap_or(&0.0);
-        let b = ((v.clamp(0.0, 100.0) / 100.0) * 7.0).round() as usize;
-        out.push(BARS[b.min(7)]);
-        idx += step;
-        if cidx + 1 >= cores.len() && out.len() < width {
-            out.push(' ');
+    // Average cores into at most `width` buckets so every core contributes
+    let buckets = cores.len().min(width);
+    let mut out = String::with_capacity(width);
+    for b in 0..buckets {
+        let start = b * cores.len() / buckets;
+        let end = ((b + 1) * cores.len() / buckets).max(start + 1);
+        let slice = &cores[start..end];
+        let avg = slice.iter().map(|c| c.usage).sum::<f32>() / slice.len() as f32;
+        let idx = ((avg.clamp(0.0, 100.0) / 100.0) * 7.0).round() as usize;
+        out.push(BARS[idx.min(7)]);
+    }
+    out
+}
+
+/// Per-core heatmap that picks the densest cell format still fitting the area
+fn render_core_heatmap(frame: &mut Frame, area: Rect, app: &App) {
+    let cores = &app.data.system.cpu_cores;
+    if area.height == 0 || area.width == 0 {
+        return;
+    }
+
+    let alerts = &app.system_alerts;
+    let avg = if cores.is_empty() {
+        0.0
+    } else {
+        cores.iter().map(|c| c.usage).sum::<f32>() / cores.len() as f32
+    };
+    let max = cores.iter().map(|c| c.usage).fold(0.0_f32, f32::max);
+    let freqs = cores
+        .iter()
+        .map(|c| c.frequency_mhz)
+        .filter(|f| *f > 0)
+        .collect::<Vec<_>>();
+
+    let mut header = vec![
+        Span::styled("cores ", Style::default().fg(MUTED)),
+        Span::styled(
+            format!("{}", cores.len()),
+            Style::default().fg(TEXT).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("  avg ", Style::default().fg(MUTED)),
+        Span::styled(
+            format!("{avg:.0}%"),
+            Style::default().fg(utilization_color(
+                avg,
+                alerts.cpu_warn_pct,
+                alerts.cpu_crit_pct,
+            )),
+        ),
+        Span::styled("  max ", Style::default().fg(MUTED)),
+        Span::styled(
+            format!("{max:.0}%"),
+            Style::default().fg(utilization_color(
+                max,
+                alerts.cpu_warn_pct,
+                alerts.cpu_crit_pct,
+            )),
+        ),
+    ];
+    if !freqs.is_empty() {
+        let avg_mhz = freqs.iter().sum::<u64>() / freqs.len() as u64;
+        header.push(Span::styled("  freq ", Style::default().fg(MUTED)));
+        header.push(Span::styled(
+            format_mhz(avg_mhz),
+            Style::default().fg(ACCENT_BRIGHT),
+        ));
+    }
+    header.push(Span::styled("  [c] metrics", Style::default().fg(TEXT_DIM)));
+    frame.render_widget(
+        Paragraph::new(Line::from(header)),
+        Rect { height: 1, ..area },
+    );
+
+    if cores.is_empty() {
+        return;
+    }
+
+    let grid = Rect {
+        y: area.y + 1,
+        height: area.height.saturating_sub(1),
+        ..area
+    };
+    let width = grid.width as usize;
+    let height = grid.height as usize;
+    let show_freq = !freqs.is_empty();
+
+    // Cell formats from most to least verbose: label + bar + pct (+ freq), label + pct, block
+    let formats: [(usize, u8); 4] = [(if show_freq { 20 } else { 14 }, 3), (9, 2), (4, 1), (1, 0)];
+    let (cell_w, detail) = formats
+        .iter()
+        .copied()
+        .find(|(w, _)| {
+            let cols = (width / w).max(1);
+            cores.len().div_ceil(cols) <= height
+        })
+        .unwrap_or((1, 0));
+    let cols = (width / cell_w).max(1);
+
+    let mut lines = Vec::new();
+    for (row_idx, chunk) in cores.chunks(cols).take(height).enumerate() {
+        let mut spans = Vec::with_capacity(chunk.len() * 2);
+        for (col_idx, core) in chunk.iter().enumerate() {
+            let idx = row_idx * cols + col_idx;
+            let color = heat_color(core.usage, alerts);
+            match detail {
+                3 => {
+                    spans.push(Span::styled(
+                        format!("{idx:>3} "),
+                        Style::default().fg(MUTED),
+                    ));
+                    spans.push(Span::styled(
+                        format!("{} {:>3.0}%", heat_bar(core.usage, 4), core.usage),
+                        Style::default().fg(color),
+                    ));
+                    if show_freq {
+                        spans.push(Span::styled(
+                            format!(" {:>5}", format_mhz(core.frequency_mhz)),
+                            Style::default().fg(TEXT_DIM),
+                        ));
+                    }
+                    spans.push(Span::styled(" ", Style::default()));
+                }
+                2 => {
+                    spans.push(Span::styled(
+                        format!("{idx:>3} "),
+                        Style::default().fg(MUTED),
+                    ));
+                    spans.push(Span::styled(
+                        format!("{:>3.0}% ", core.usage),
+                        Style::default().fg(color),
+                    ));
+                }
+                1 => spans.push(Span::styled(
+                    format!("{:>3.0} ", core.usage),
+                    Style::default().fg(TEXT).bg(color),
+                )),
+                _ => spans.push(Span::styled(" ", Style::default().bg(color))),
+            }
+        }
+        lines.push(Line::from(spans));
+    }
+
+    frame.render_widget(Paragraph::new(lines), grid);
+}
+
+fn heat_color(usage: f32, alerts: &SystemAlerts) -> Color {
+    if usage < 10.0 {
+        MUTED
+    } else if usage < alerts.cpu_warn_pct / 2.0 {
+        GOOD
+    } else {
+        utilization_color(usage, alerts.cpu_warn_pct, alerts.cpu_crit_pct)
+    }
+}
+
+fn heat_bar(usage: f32, width: usize) -> String {
+    let fill = ((usage.clamp(0.0, 100.0) / 100.0) * width as f32).round() as usize;
+    let mut out = "█".repeat(fill.min(width));
+    out.push_str(&"·".repeat(width - fill.min(width)));
+    out
+}
+
+fn format_mhz(mhz: u64) -> String {
+    if mhz >= 1000 {
+        format!("{:.1}G", mhz as f64 / 1000.0)
+    } else {
+        format!("{mhz}M")
+    }
+}
+
+fn pressure_line(sys: &SystemStatus, alerts: &SystemAlerts) -> Line<'static> {
+    let mut spans = vec![Span::styled("psi ", Style::default().fg(MUTED))];
+
+    match &sys.pressure {
+        Some(p) => {
+            for (label, avg) in [("cpu", p.cpu), ("mem", p.memory), ("io", p.io)] {
+                spans.push(Span::styled(
+                    format!("{label} "),
+                    Style::default().fg(MUTED),
+                ));
+                spans.push(psi_span(avg));
+                spans.push(Span::styled(" ", Style::default()));
+            }
         }
+        None => spans.push(Span::styled("n/a ", Style::default().fg(TEXT_DIM))),
     }
-    out.chars().take(width).collect()
+
+    if let Some(cg) = &sys.cgroup {
+        spans.push(Span::styled(" cg ", Style::default().fg(MUTED)));
+        if let (Some(pct), Some(max)) = (cg.memory_pct(), cg.memory_max_bytes) {
+            let pct = pct as f32;
+            spans.push(Span::styled(
+                format!(
+                    "mem {:.0}% {:.1}/{:.1}G",
+                    pct,
+                    cg.memory_current_bytes as f64 / 1024.0 / 1024.0 / 1024.0,
+                    max as f64 / 1024.0 / 1024.0 / 1024.0
+                ),
+                Style::default().fg(utilization_color(
+                    pct,
+                    alerts.mem_warn_pct,
+                    alerts.mem_crit_pct,
+                )),
+            ));
+            spans.push(Span::styled(" ", Style::default()));
+        }
+        if let Some(quota) = cg.cpu_quota_cores {
+            let pct = cg.cpu_pct_of_quota().unwrap_or(0.0) as f32;
+            spans.push(Span::styled(
+                format!("cpu {:.0}%/{:.1}c", pct, quota),
+                Style::default().fg(utilization_color(
+                    pct,
+                    alerts.cpu_warn_pct,
+                    alerts.cpu_crit_pct,
+                )),
+            ));
+        }
+    }
+
+    Line::from(spans)
+}
+
+fn psi_span(avg: Option<PsiAverages>) -> Span<'static> {
+    let Some(avg) = avg else {
+        return Span::styled("--", Style::default().fg(TEXT_DIM));
+    };
+    let color = if avg.avg10 < 5.0 {
+        GOOD
+    } else if avg.avg10 < 20.0 {
+        WARN
+    } else {
+        BAD
+    };
+    Span::styled(
+        format!("{:.1}/{:.1}", avg.avg10, avg.avg60),
+        Style::default().fg(color),
+    )
 }
 
 fn format_duration_short(secs: u64) -> String {
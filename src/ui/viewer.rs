@@ -0,0 +1,110 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use crate::app::LogViewer;
+
+use super::panes::log_line;
+use super::theme::{
+    ACCENT_BRIGHT, BG, BORDER_FOCUSED, GLOW, GOOD_BRIGHT, TEXT, TEXT_DIM, WARN_BRIGHT,
+};
+
+pub fn render_log_viewer(frame: &mut Frame, viewer: &LogViewer) {
+    let area = frame.area().inner(Margin {
+        vertical: 1,
+        horizontal: 1,
+    });
+    frame.render_widget(Clear, area);
+
+    let (mode, mode_color) = if viewer.buffer.paused {
+        (format!("PAUSED -{}", viewer.buffer.scroll), WARN_BRIGHT)
+    } else {
+        ("FOLLOW".to_string(), GOOD_BRIGHT)
+    };
+    let total = viewer.buffer.iter().count();
+    let title = Line::from(vec![
+        Span::styled(
+            format!(" LOGS {} ", viewer.container_name),
+            Style::default()
+                .fg(ACCENT_BRIGHT)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("· {} · {total} lines · ", viewer.window().label),
+            Style::default().fg(TEXT_DIM),
+        ),
+        Span::styled(format!("{mode} "), Style::default().fg(mode_color)),
+    ]);
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(BORDER_FOCUSED))
+        .style(Style::default().bg(BG));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+
+    let search = viewer.search.trim();
+    let lines = viewer.buffer.iter().collect::<Vec<_>>();
+    let height = rows[0].height as usize;
+    let end = lines.len().saturating_sub(viewer.buffer.scroll);
+    let start = end.saturating_sub(height);
+    let body = lines[start..end]
+        .iter()
+        .map(|l| log_line(l, false, search))
+        .collect::<Vec<_>>();
+    frame.render_widget(Paragraph::new(body), rows[0]);
+
+    let footer = if viewer.search_mode {
+        Line::from(vec![
+            Span::styled(
+                "/ ",
+                Style::default()
+                    .fg(ACCENT_BRIGHT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(viewer.search.clone(), Style::default().fg(TEXT)),
+            Span::styled("|", Style::default().fg(ACCENT_BRIGHT)),
+            Span::styled(
+                "   [Enter] keep  [Esc] clear",
+                Style::default().fg(TEXT_DIM),
+            ),
+        ])
+    } else {
+        let mut spans = Vec::new();
+        for (key, label) in [
+            ("[Space]", "Pause"),
+            ("[G]", "Follow"),
+            ("[/]", "Search"),
+            ("[n/N]", "Match"),
+            ("[t]", "Window"),
+            ("[Esc]", "Close"),
+        ] {
+            spans.push(Span::styled(
+                format!("{key} "),
+                Style::default().fg(GLOW).add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::styled(
+                format!("{label}  "),
+                Style::default().fg(TEXT_DIM),
+            ));
+        }
+        if !search.is_empty() {
+            spans.push(Span::styled(
+                format!("/{search}"),
+                Style::default().fg(WARN_BRIGHT),
+            ));
+        }
+        Line::from(spans)
+    };
+    frame.render_widget(Paragraph::new(footer), rows[1]);
+}